@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::fixture::generate_tests("fixtures");
+}
@@ -1,4 +1,5 @@
-use std::fs;
+use aoc_common::parse::ParseError;
+use aoc_common::puzzle::Solution;
 
 fn pt1_calibration_sum(lines: Vec<String>) -> u32 {
     lines
@@ -6,7 +7,7 @@ fn pt1_calibration_sum(lines: Vec<String>) -> u32 {
         .map(|entry| {
             let digits: Vec<u32> = entry
                 .chars()
-                .filter(|char| char.is_digit(10))
+                .filter(|char| char.is_ascii_digit())
                 .map(|char| char.to_digit(10).unwrap())
                 .collect();
 
@@ -36,7 +37,7 @@ fn pt2_calibration_sum(lines: Vec<String>) -> u32 {
                 .replace("eight", "8")
                 .replace("nine", "9")
                 .chars()
-                .filter(|char| char.is_digit(10))
+                .filter(|char| char.is_ascii_digit())
                 .map(|char| char.to_digit(10).unwrap())
                 .collect();
 
@@ -45,10 +46,25 @@ fn pt2_calibration_sum(lines: Vec<String>) -> u32 {
         .sum()
 }
 
-fn main() {
-    let contents = fs::read_to_string("inputs/day1.txt").expect("Failed to read input");
-    let lines = contents.lines().map(|line| line.to_string());
+pub struct Document {
+    lines: Vec<String>,
+}
+
+impl Solution for Document {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            lines: input.lines().map(|line| line.to_string()).collect(),
+        })
+    }
+
+    fn part_1(&self) -> u32 {
+        pt1_calibration_sum(self.lines.clone())
+    }
 
-    println!("Part 1: {}", pt1_calibration_sum(lines.clone().collect()));
-    println!("Part 2: {}", pt2_calibration_sum(lines.clone().collect()));
+    fn part_2(&self) -> u32 {
+        pt2_calibration_sum(self.lines.clone())
+    }
 }
@@ -1,12 +1,13 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
 use std::collections::HashMap;
 use std::fmt::Debug;
-use std::fs;
 
 type Loc = (usize, usize);
 type Vect = (i8, i8);
 
 #[derive(Debug, Eq, PartialEq)]
-struct Tile {
+pub struct Tile {
     x: usize,
     y: usize,
     kind: char,
@@ -57,15 +58,15 @@ impl Tile {
 type StepsMap = HashMap<Loc, usize>;
 
 #[derive(Debug)]
-struct Map {
+pub struct Map {
     tiles: HashMap<Loc, Tile>,
     width: usize,
     _height: usize,
 }
 
 impl Map {
-    pub fn load(contents: String) -> Self {
-        Self {
+    pub fn load(contents: &str) -> Result<Self, ParseError> {
+        let map = Self {
             tiles: contents
                 .lines()
                 .enumerate()
@@ -75,8 +76,22 @@ impl Map {
                         .map(move |(x, char)| ((x, y), Tile { x, y, kind: char }))
                 })
                 .collect(),
-            width: contents.find("\n").unwrap(),
+            width: contents
+                .lines()
+                .next()
+                .map_or(0, |line| line.chars().count()),
             _height: contents.lines().count(),
+        };
+
+        let Some(start) = contents.find('S') else {
+            return Err(ParseError::after(contents.trim_end(), "Missing start tile"));
+        };
+        match map.connecting_pipes(map.start_tile()).len() {
+            2.. => Ok(map),
+            _ => Err(ParseError::at(
+                parse::char_at(contents, start),
+                "Start tile is not on a loop",
+            )),
         }
     }
 
@@ -232,7 +247,7 @@ impl Map {
     }
 
     pub fn furthest_tile(&self) -> usize {
-        self.steps_map().values().max().unwrap().clone()
+        *self.steps_map().values().max().unwrap()
     }
 
     pub fn enclosed_tiles(&self) -> usize {
@@ -243,112 +258,74 @@ impl Map {
     }
 }
 
-fn main() {
-    // 7030
-    println!(
-        "Part 1: {}",
-        Map::load(fs::read_to_string("inputs/day10.txt").unwrap()).furthest_tile()
-    );
-    // 285
-    println!(
-        "Part 2: {}",
-        Map::load(fs::read_to_string("inputs/day10.txt").unwrap()).enclosed_tiles()
-    );
+impl Solution for Map {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::load(input)
+    }
+
+    fn part_1(&self) -> usize {
+        self.furthest_tile()
+    }
+
+    fn part_2(&self) -> usize {
+        self.enclosed_tiles()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::fixture;
 
     #[test]
     fn test_tile() {
-        assert_eq!(
-            Tile {
-                kind: '7',
-                x: 91,
-                y: 21
-            }
-            .connects_to(&Tile {
-                kind: '-',
-                x: 90,
-                y: 20
-            }),
-            true
-        );
+        assert!(Tile {
+            kind: '7',
+            x: 91,
+            y: 21
+        }
+        .connects_to(&Tile {
+            kind: '-',
+            x: 90,
+            y: 20
+        }));
+    }
+
+    #[test]
+    fn test_load() {
+        assert!(Map::load(".....\n.S-7.\n.|.|.\n.L-J.\n").is_ok());
+        assert!(Map::load(".....\n.S-7.\n").is_err());
+        assert!(Map::load(".F-7.\n").is_err());
     }
 
     #[test]
     fn test_directions_map() {
-        let map = Map::load(fs::read_to_string("samples/day10c.txt").unwrap());
+        let map = Map::load(&fixture!("day10c").input).unwrap();
         let directions_map = map.direction_map();
 
-        assert_eq!(map.is_clockwise(&directions_map), false);
+        assert!(!map.is_clockwise(&directions_map));
 
         // .|L-7OF-J|.
         // .|II|O|II|.
         // .L--JOL--J.
 
-        let tile_6_5 = directions_map.get(&(6, 5)).unwrap(); // F tile
-        assert_eq!(tile_6_5.0, -1); // leftwards
-        assert_eq!(tile_6_5.1, 0);
-
-        let tile_6_6 = directions_map.get(&(6, 6)).unwrap(); // | tile
-        assert_eq!(tile_6_6.0, 0);
-        assert_eq!(tile_6_6.1, 1); // downwards
-
-        let tile_6_7 = directions_map.get(&(6, 7)).unwrap(); // L tile
-        assert_eq!(tile_6_7.0, 0);
-        assert_eq!(tile_6_7.1, 1); // downwards
+        // Bends hold the sum of their incoming and outgoing deltas
+        assert_eq!(directions_map.get(&(6, 5)), Some(&(-1, 1))); // F tile: leftwards, then down
+        assert_eq!(directions_map.get(&(6, 6)), Some(&(0, 1))); // | tile: downwards
+        assert_eq!(directions_map.get(&(6, 7)), Some(&(1, 1))); // L tile: downwards, then right
 
-        let map = Map::load(fs::read_to_string("samples/day10e.txt").unwrap());
+        let map = Map::load(&fixture!("day10e").input).unwrap();
         let directions_map = map.direction_map();
 
-        assert_eq!(map.is_clockwise(&directions_map), true);
+        assert!(map.is_clockwise(&directions_map));
 
         // L7
         // IL
-        let tile_14_5 = directions_map.get(&(14, 5)).unwrap(); // L tile
-        assert_eq!(tile_14_5.0, 0); // rightwards
-        assert_eq!(tile_14_5.1, 1);
-
-        let tile_15_5 = directions_map.get(&(15, 5)).unwrap(); // 7 tile
-        assert_eq!(tile_15_5.0, 1); // rightwards
-        assert_eq!(tile_15_5.1, 0);
-
-        let tile_15_6 = directions_map.get(&(15, 6)).unwrap(); // L tile
-        assert_eq!(tile_15_6.0, 0);
-        assert_eq!(tile_15_6.1, 1); // downwards
-    }
-
-    #[test]
-    fn test_part1() {
-        assert_eq!(
-            Map::load(fs::read_to_string("samples/day10a.txt").unwrap()).furthest_tile(),
-            4
-        );
-        assert_eq!(
-            Map::load(fs::read_to_string("samples/day10b.txt").unwrap()).furthest_tile(),
-            8
-        );
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(
-            Map::load(fs::read_to_string("samples/day10c.txt").unwrap()).enclosed_tiles(),
-            4
-        );
-        assert_eq!(
-            Map::load(fs::read_to_string("samples/day10d.txt").unwrap()).enclosed_tiles(),
-            4
-        );
-        assert_eq!(
-            Map::load(fs::read_to_string("samples/day10e.txt").unwrap()).enclosed_tiles(),
-            8
-        );
-        assert_eq!(
-            Map::load(fs::read_to_string("samples/day10f.txt").unwrap()).enclosed_tiles(),
-            10
-        );
+        assert_eq!(directions_map.get(&(14, 5)), Some(&(1, 1))); // L tile: downwards, then right
+        assert_eq!(directions_map.get(&(15, 5)), Some(&(1, 1))); // 7 tile: rightwards, then down
+        assert_eq!(directions_map.get(&(15, 6)), Some(&(1, 1))); // L tile: downwards, then right
     }
 }
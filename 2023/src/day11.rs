@@ -1,9 +1,11 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
 use std::cell::Cell;
 use std::fmt::Debug;
-use std::fs;
 
-#[derive(Debug)]
-struct Galaxy {
+#[derive(Debug, Clone)]
+pub struct Galaxy {
+    #[allow(dead_code)] // only used to look up galaxies in tests
     number: u32,
     x: Cell<usize>,
     y: Cell<usize>,
@@ -15,45 +17,49 @@ impl Galaxy {
     }
 }
 
-#[derive(Debug)]
-struct Universe {
+#[derive(Debug, Clone)]
+pub struct Universe {
     galaxies: Vec<Galaxy>,
     width: usize,
     height: usize,
 }
 
 impl Universe {
-    pub fn load(contents: String) -> Self {
+    pub fn load(contents: &str) -> Result<Self, ParseError> {
         let mut galaxy_count: u32 = 0;
 
-        Self {
+        Ok(Self {
             galaxies: contents
                 .lines()
                 .enumerate()
                 .flat_map(|(y, line)| {
-                    line.chars()
+                    line.char_indices()
                         .enumerate()
-                        .filter_map(|(x, char)| {
-                            if char != '#' {
-                                return None;
-                            }
-
-                            galaxy_count += 1;
-
-                            Some(Galaxy {
-                                number: galaxy_count,
-                                x: Cell::new(x),
-                                y: Cell::new(y),
-                            })
-                        })
-                        .collect::<Vec<Galaxy>>()
+                        .map(move |(x, (i, char))| (x, y, parse::char_at(line, i), char))
                 })
-                .collect(),
-            width: contents.find("\n").unwrap(),
-            height: contents.lines().count() - 1,
-        }
+                .filter_map(|(x, y, snippet, char)| match char {
+                    '.' => None,
+                    '#' => {
+                        galaxy_count += 1;
+
+                        Some(Ok(Galaxy {
+                            number: galaxy_count,
+                            x: Cell::new(x),
+                            y: Cell::new(y),
+                        }))
+                    }
+                    _ => Some(Err(ParseError::at(snippet, "Invalid space"))),
+                })
+                .collect::<Result<_, _>>()?,
+            width: contents
+                .lines()
+                .next()
+                .map_or(0, |line| line.chars().count()),
+            height: contents.lines().count().saturating_sub(1),
+        })
     }
 
+    #[cfg(test)]
     pub fn get_galaxy(&self, number: u32) -> &Galaxy {
         self.galaxies
             .iter()
@@ -106,27 +112,35 @@ impl Universe {
     }
 }
 
-fn main() {
-    let universe1 = Universe::load(fs::read_to_string("inputs/day11.txt").unwrap());
-    universe1.expand(2);
+impl Solution for Universe {
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    // 10313550
-    println!("Part 1: {}", universe1.sum_shortest_paths());
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::load(input)
+    }
 
-    let universe2 = Universe::load(fs::read_to_string("inputs/day11.txt").unwrap());
-    universe2.expand(1000000);
+    fn part_1(&self) -> usize {
+        let universe = self.clone();
+        universe.expand(2);
+        universe.sum_shortest_paths()
+    }
 
-    // 611998089572
-    println!("Part 2: {}", universe2.sum_shortest_paths());
+    fn part_2(&self) -> usize {
+        let universe = self.clone();
+        universe.expand(1000000);
+        universe.sum_shortest_paths()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::fixture;
 
     #[test]
-    fn test_part1() {
-        let universe = Universe::load(fs::read_to_string("samples/day11.txt").unwrap());
+    fn test_shortest_path() {
+        let universe = Universe::load(&fixture!("day11").input).unwrap();
         universe.expand(2);
 
         assert_eq!(
@@ -141,16 +155,15 @@ mod tests {
             universe.get_galaxy(8).shortest_path(universe.get_galaxy(9)),
             5
         );
-        assert_eq!(universe.sum_shortest_paths(), 374);
     }
 
     #[test]
-    fn test_part2() {
-        let universe1 = Universe::load(fs::read_to_string("samples/day11.txt").unwrap());
+    fn test_expand() {
+        let universe1 = Universe::load(&fixture!("day11").input).unwrap();
         universe1.expand(10);
         assert_eq!(universe1.sum_shortest_paths(), 1030);
 
-        let universe2 = Universe::load(fs::read_to_string("samples/day11.txt").unwrap());
+        let universe2 = Universe::load(&fixture!("day11").input).unwrap();
         universe2.expand(100);
         assert_eq!(universe2.sum_shortest_paths(), 8410);
     }
@@ -0,0 +1,171 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+#[derive(Debug, Clone)]
+struct Record {
+    sequence: String,
+    groups: Vec<usize>,
+}
+
+impl Record {
+    pub fn load(contents: &str) -> Result<Self, ParseError> {
+        let (sequence, groups) = parse::split_once(contents, " ")?;
+
+        if let Some(i) = sequence.find(|char| !".#?".contains(char)) {
+            return Err(ParseError::at(
+                parse::char_at(sequence, i),
+                "Invalid spring",
+            ));
+        }
+
+        Ok(Self {
+            sequence: sequence.to_string(),
+            groups: groups
+                .split(',')
+                .map(parse::number)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Record with its springs and groups repeated the given number of times, with the copies
+    /// of the springs joined by unknown ones
+    pub fn unfold(&self, times: usize) -> Self {
+        Self {
+            sequence: vec![self.sequence.as_str(); times].join("?"),
+            groups: self.groups.repeat(times),
+        }
+    }
+
+    pub fn arrangements(&self) -> usize {
+        self.count(0, 0, 0, &mut HashMap::new())
+    }
+
+    /// Number of ways to fill in the springs from the given position on, with the groups
+    /// before the given one already complete and a run of broken springs of the given length
+    /// ending just before the position
+    fn count(
+        &self,
+        position: usize,
+        group: usize,
+        run: usize,
+        cache: &mut HashMap<(usize, usize, usize), usize>,
+    ) -> usize {
+        let Some(&spring) = self.sequence.as_bytes().get(position) else {
+            let complete = match run {
+                0 => group == self.groups.len(),
+                _ => group + 1 == self.groups.len() && run == self.groups[group],
+            };
+            return complete as usize;
+        };
+        if let Some(&count) = cache.get(&(position, group, run)) {
+            return count;
+        }
+
+        let mut count = 0;
+        if spring != b'#' {
+            if run == 0 {
+                count += self.count(position + 1, group, 0, cache);
+            } else if run == self.groups[group] {
+                count += self.count(position + 1, group + 1, 0, cache);
+            }
+        }
+        if spring != b'.' && self.groups.get(group).is_some_and(|&length| run < length) {
+            count += self.count(position + 1, group, run + 1, cache);
+        }
+
+        cache.insert((position, group, run), count);
+        count
+    }
+}
+
+#[derive(Debug)]
+pub struct Puzzle {
+    records: Vec<Record>,
+}
+
+impl Puzzle {
+    pub fn load(contents: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            records: contents
+                .lines()
+                .map(Record::load)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl Solution for Puzzle {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::load(input)
+    }
+
+    fn part_1(&self) -> usize {
+        self.records
+            .iter()
+            .fold(0, |acc, record| acc + record.arrangements())
+    }
+
+    fn part_2(&self) -> usize {
+        self.records
+            .iter()
+            .map(|record| record.unfold(5).arrangements())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixture;
+
+    #[test]
+    fn test_arrangements() {
+        assert_eq!(Record::load("???.### 1,1,3").unwrap().arrangements(), 1);
+        assert_eq!(
+            Record::load(".??..??...?##. 1,1,3").unwrap().arrangements(),
+            4
+        );
+        assert_eq!(
+            Record::load("?#?#?#?#?#?#?#? 1,3,1,6")
+                .unwrap()
+                .arrangements(),
+            1
+        );
+        assert_eq!(
+            Record::load("????.#...#... 4,1,1").unwrap().arrangements(),
+            1
+        );
+        assert_eq!(
+            Record::load("????.######..#####. 1,6,5")
+                .unwrap()
+                .arrangements(),
+            4
+        );
+        assert_eq!(
+            Record::load("?###???????? 3,2,1").unwrap().arrangements(),
+            10
+        );
+    }
+
+    #[test]
+    fn test_unfolded_arrangements() {
+        let record = Record::load(".# 1").unwrap().unfold(5);
+        assert_eq!(".#?.#?.#?.#?.#", record.sequence);
+        assert_eq!(vec![1; 5], record.groups);
+
+        let puzzle = Puzzle::load(&fixture!("day12").input).unwrap();
+        assert_eq!(
+            vec![1, 16384, 1, 16, 2500, 506250],
+            puzzle
+                .records
+                .iter()
+                .map(|record| record.unfold(5).arrangements())
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -1,5 +1,6 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::{Maybe, Solution};
 use std::fmt::Debug;
-use std::fs;
 
 #[derive(Debug)]
 struct Pattern {
@@ -9,11 +10,31 @@ struct Pattern {
 }
 
 impl Pattern {
-    pub fn load(contents: String) -> Self {
+    pub fn load(contents: &str) -> Result<Self, ParseError> {
+        let width = contents.lines().next().map_or(0, str::len);
+
+        if width == 0 {
+            return Err(ParseError::at(contents, "Empty pattern"));
+        }
+        if width == 1 && contents.lines().count() == 1 {
+            return Err(ParseError::at(contents, "Pattern too small to reflect"));
+        }
+
+        for line in contents.lines() {
+            if let Some(i) = line.find(|char| char != '.' && char != '#') {
+                return Err(ParseError::at(parse::char_at(line, i), "Invalid ground"));
+            }
+            if line.len() != width {
+                return Err(ParseError::at(line, format!("Expected {} columns", width)));
+            }
+        }
+
+        Ok(Self::new(contents.to_string()))
+    }
+
+    fn new(contents: String) -> Self {
         Self {
-            contents: contents.to_string(),
-            rows: contents.lines().map(|line| line.to_string()).collect(),
-            cols: (0..contents.lines().nth(0).unwrap().len())
+            cols: (0..contents.lines().next().map_or(0, str::len))
                 .map(|col| {
                     contents
                         .lines()
@@ -21,6 +42,8 @@ impl Pattern {
                         .collect::<String>()
                 })
                 .collect(),
+            rows: contents.lines().map(|line| line.to_string()).collect(),
+            contents,
         }
     }
 
@@ -38,8 +61,8 @@ impl Pattern {
             .collect()
     }
 
-    // Returns (fixed summary, fixed pattern)
-    pub fn fix_smudge(&self) -> (usize, Self) {
+    // Returns (fixed summary, fixed pattern), unless no smudge gives a new line of reflection
+    pub fn fix_smudge(&self) -> Option<(usize, Self)> {
         self.contents
             .chars()
             .enumerate()
@@ -47,42 +70,32 @@ impl Pattern {
                 '.' | '#' => {
                     let mut fixed_contents = self.contents.clone();
                     fixed_contents.replace_range(i..i + 1, if char == '.' { "#" } else { "." });
-                    let fixed_pattern = Self::load(fixed_contents);
+                    let fixed_pattern = Self::new(fixed_contents);
 
                     let old_reflection_rows = self.reflection_rows();
                     let new_reflection_rows = fixed_pattern.reflection_rows();
 
-                    if !new_reflection_rows.is_empty() && new_reflection_rows != old_reflection_rows
+                    if let Some(rows) = new_reflection_rows
+                        .iter()
+                        .find(|rows| !old_reflection_rows.contains(rows))
                     {
-                        return Some((
-                            new_reflection_rows
-                                .iter()
-                                .find(|rows| !old_reflection_rows.contains(rows))
-                                .unwrap()
-                                * 100,
-                            fixed_pattern,
-                        ));
+                        return Some((rows * 100, fixed_pattern));
                     }
 
                     let old_reflection_cols = self.reflection_cols();
                     let new_reflection_cols = fixed_pattern.reflection_cols();
 
-                    if !new_reflection_cols.is_empty() && new_reflection_cols != old_reflection_cols
+                    if let Some(&cols) = new_reflection_cols
+                        .iter()
+                        .find(|cols| !old_reflection_cols.contains(cols))
                     {
-                        return Some((
-                            *new_reflection_cols
-                                .iter()
-                                .find(|cols| !old_reflection_cols.contains(cols))
-                                .unwrap(),
-                            fixed_pattern,
-                        ));
+                        return Some((cols, fixed_pattern));
                     }
 
                     None
                 }
                 _ => None,
             })
-            .unwrap()
     }
 
     pub fn summary(&self) -> usize {
@@ -90,7 +103,7 @@ impl Pattern {
             + self.reflection_cols().first().unwrap_or(&0usize)
     }
 
-    fn reflection_indices(&self, lines: &Vec<String>) -> Vec<usize> {
+    fn reflection_indices(&self, lines: &[String]) -> Vec<usize> {
         lines[..lines.len() - 1] // All except the last (which has no following lines to compare with)
             .iter()
             .enumerate()
@@ -120,58 +133,59 @@ impl Pattern {
 }
 
 #[derive(Debug)]
-struct Puzzle {
+pub struct Puzzle {
     patterns: Vec<Pattern>,
 }
 
 impl Puzzle {
-    pub fn load(contents: String) -> Self {
-        Self {
+    pub fn load(contents: &str) -> Result<Self, ParseError> {
+        Ok(Self {
             patterns: contents
+                .trim_end()
                 .split("\n\n")
-                .map(|section| Pattern::load(section.to_string()))
-                .collect(),
-        }
+                .map(Pattern::load)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl Solution for Puzzle {
+    type Answer1 = usize;
+    type Answer2 = Maybe<usize>;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::load(input)
     }
 
-    pub fn part1(&self) -> usize {
+    fn part_1(&self) -> usize {
         self.patterns
             .iter()
             .fold(0, |acc, pattern| acc + pattern.summary())
     }
 
-    pub fn part2(&self) -> usize {
+    /// No answer when a pattern has no smudge to fix
+    fn part_2(&self) -> Maybe<usize> {
         self.patterns
             .iter()
-            .fold(0, |acc, pattern| acc + pattern.fix_smudge().0)
+            .map(|pattern| pattern.fix_smudge().map(|(summary, _)| summary))
+            .sum::<Option<usize>>()
+            .into()
     }
 }
 
-fn main() {
-    // 29846
-    println!(
-        "Part 1: {}",
-        Puzzle::load(fs::read_to_string("inputs/day13.txt").unwrap()).part1()
-    );
-    // 25401
-    println!(
-        "Part 2: {}",
-        Puzzle::load(fs::read_to_string("inputs/day13.txt").unwrap()).part2()
-    );
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::fixture;
 
     #[test]
     fn test_reflection() {
-        let puzzle = &Puzzle::load(fs::read_to_string("samples/day13a.txt").unwrap());
+        let puzzle = &Puzzle::load(&fixture!("day13a").input).unwrap();
 
         assert_eq!(puzzle.patterns[0].reflection_cols(), vec![5]);
         assert_eq!(puzzle.patterns[1].reflection_rows(), vec![4]);
 
-        let puzzle = &Puzzle::load(fs::read_to_string("samples/day13b.txt").unwrap());
+        let puzzle = &Puzzle::load(&fixture!("day13b").input).unwrap();
 
         assert_eq!(puzzle.patterns[0].reflection_rows(), vec![]);
         assert_eq!(puzzle.patterns[0].reflection_cols(), vec![3]);
@@ -186,25 +200,28 @@ mod tests {
     }
 
     #[test]
-    fn test_part1() {
-        assert_eq!(
-            Puzzle::load(fs::read_to_string("samples/day13a.txt").unwrap()).part1(),
-            405
-        );
+    fn test_load() {
+        assert!(Pattern::load("#.\n").is_ok());
+        assert!(Pattern::load("#\n").is_err());
+        assert!(Pattern::load("#.\n#\n").is_err());
     }
 
     #[test]
-    fn test_part2() {
-        let puzzle = &Puzzle::load(fs::read_to_string("samples/day13a.txt").unwrap());
+    fn test_fix_smudge() {
+        let puzzle = &Puzzle::load(&fixture!("day13a").input).unwrap();
 
-        assert_eq!(puzzle.patterns[0].fix_smudge().1.reflection_rows(), vec![3]);
-        assert_eq!(puzzle.patterns[0].fix_smudge().1.reflection_cols(), vec![5]); // still a valid reflection after fix
+        assert_eq!(
+            puzzle.patterns[0].fix_smudge().unwrap().1.reflection_rows(),
+            vec![3]
+        );
+        assert_eq!(
+            puzzle.patterns[0].fix_smudge().unwrap().1.reflection_cols(),
+            vec![5]
+        ); // still a valid reflection after fix
 
         assert_eq!(
-            puzzle.patterns[1].fix_smudge().1.reflection_rows(),
+            puzzle.patterns[1].fix_smudge().unwrap().1.reflection_rows(),
             vec![1, 4]
         );
-
-        assert_eq!(puzzle.part2(), 400);
     }
 }
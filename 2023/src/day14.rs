@@ -1,78 +1,47 @@
+use aoc_common::grid::Grid;
+use aoc_common::parse::ParseError;
+use aoc_common::puzzle::Solution;
+use aoc_common::render::{Frame, Rgb};
 use std::fmt::Debug;
-use std::fs;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-struct Platform {
-    rows: Vec<String>,
+pub struct Platform {
+    grid: Grid<char>,
 }
 
 impl Platform {
-    pub fn load(contents: String) -> Self {
-        Self {
-            rows: contents
-                .trim()
-                .lines()
-                .map(|line| line.to_string())
-                .collect(),
-        }
+    pub fn load(contents: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            grid: Grid::parse_with(contents, |char| match char {
+                'O' | '#' | '.' => Ok(char),
+                _ => Err("Invalid rock"),
+            })?,
+        })
     }
 
     pub fn rotate_ccw(&self) -> Self {
         Self {
-            rows: (0..self.rows[0].len())
-                .rev()
-                .map(|col| {
-                    self.rows
-                        .iter()
-                        .map(|line| line[col..col + 1].to_string())
-                        .collect::<String>()
-                })
-                .collect(),
+            grid: self.grid.rotate_ccw(),
         }
     }
 
     pub fn rotate_cw(&self) -> Self {
         Self {
-            rows: (0..self.rows[0].len())
-                .map(|col| {
-                    self.rows
-                        .iter()
-                        .map(|line| line[col..col + 1].to_string())
-                        .rev()
-                        .collect::<String>()
-                })
-                .collect(),
+            grid: self.grid.rotate_cw(),
         }
     }
 
     pub fn move_boulders_left(&self) -> Self {
-        Self {
-            rows: self
-                .rows
-                .iter()
-                .map(|row| {
-                    row.chars()
-                        .enumerate()
-                        .fold(String::new(), |mut acc, (i, char)| {
-                            if char == '#' {
-                                // push all occurrences of rocks(#) unaltered
-                                acc.push(char);
-                            }
-                            // First boulder(O) or open space(.), or one preceded by a rock (#)
-                            else if i == 0 || &row[i.saturating_sub(1)..i] == "#" {
-                                let mut captured = row[i..]
-                                    .chars()
-                                    .take_while(|&cap_char| cap_char != '#')
-                                    .collect::<Vec<char>>();
-                                captured.sort_by(|a, b| b.cmp(a));
-                                acc.push_str(&captured.iter().collect::<String>());
-                            }
-
-                            acc
-                        })
-                })
-                .collect(),
-        }
+        let mut grid = self.grid.clone();
+
+        // Rocks (#) stay in place, so sort each stretch between them to put the boulders (O)
+        // before the open spaces (.)
+        grid.rows_mut().for_each(|row| {
+            row.split_mut(|&char| char == '#')
+                .for_each(|stretch| stretch.sort_by(|a, b| b.cmp(a)))
+        });
+
+        Self { grid }
     }
 
     pub fn slide_north(&self) -> Self {
@@ -103,35 +72,77 @@ impl Platform {
         current.rotate_cw()
     }
 
+    /// Platform after every tilt of the given number of spin cycles, in the original orientation
+    pub fn tilts(&self, cycles: usize) -> Vec<Self> {
+        // Tilting is done by moving the boulders left, so north starts out on the left. Every
+        // tilt is followed by a clockwise rotation, after which these rotations restore the
+        // original orientation.
+        let mut current = self.rotate_ccw();
+        let mut tilts = vec![];
+
+        for _ in 0..cycles {
+            for rotations in [1, 0, 3, 2] {
+                current = current.move_boulders_left();
+                tilts.push((0..rotations).fold(current.clone(), |acc, _| acc.rotate_cw()));
+                current = current.rotate_cw();
+            }
+        }
+
+        tilts
+    }
+
+    pub fn frame(&self) -> Frame {
+        self.grid.map(|&char| match char {
+            'O' => Rgb::WHITE,
+            '#' => Rgb::GREY,
+            _ => Rgb::BLACK,
+        })
+    }
+
     pub fn total_load_north(&self) -> usize {
-        self.rows
-            .iter()
-            .zip((0..self.rows.len() + 1).rev())
+        self.grid
+            .rows()
+            .zip((1..=self.grid.height()).rev())
             .fold(0, |acc, (row, weight)| {
-                acc + row.chars().filter(|&char| char == 'O').count() * weight
+                acc + row.iter().filter(|&&char| char == 'O').count() * weight
             })
     }
 }
 
-fn main() {
-    let platform = Platform::load(fs::read_to_string("inputs/day14.txt").unwrap());
+impl Solution for Platform {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::load(input)
+    }
+
+    fn part_1(&self) -> usize {
+        self.slide_north().total_load_north()
+    }
+
+    fn part_2(&self) -> usize {
+        self.slide_cycle(1_000_000_000).total_load_north()
+    }
+
+    /// The first spin cycles, tilting north, west, south and east
+    fn render(&self, frame: &mut dyn FnMut(Frame)) {
+        frame(self.frame());
 
-    // 113424
-    println!("Part 1: {}", platform.slide_north().total_load_north());
-    // 96003
-    println!(
-        "Part 2: {}",
-        platform.slide_cycle(1_000_000_000).total_load_north()
-    );
+        for platform in self.tilts(3) {
+            frame(platform.frame());
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::fixture;
 
     #[test]
     fn test_common() {
-        let platform = Platform::load(fs::read_to_string("samples/day14.txt").unwrap());
+        let platform = Platform::load(&fixture!("day14").input).unwrap();
 
         assert_eq!(
             Platform::load(
@@ -147,8 +158,8 @@ mod tests {
 #....###.O
 #.OOO#..OO
         "#
-                .to_string()
             )
+            .unwrap()
             .total_load_north(),
             64
         );
@@ -183,8 +194,8 @@ O..O#...O.
 .....#....
 ...O#.O.#.
 "#
-                .to_string()
             )
+            .unwrap()
         );
 
         // Test given cycles
@@ -203,8 +214,8 @@ O..O#...O.
 #...O###..
 #..OO#....
 "#
-                .to_string()
             )
+            .unwrap()
         );
 
         assert_eq!(
@@ -222,8 +233,8 @@ O..O#...O.
 #..OO###..
 #.OOO#...O
             "#
-                .to_string()
             )
+            .unwrap()
         );
 
         assert_eq!(
@@ -241,28 +252,19 @@ O..O#...O.
 #...O###.O
 #.OOO#...O
 "#
-                .to_string()
             )
+            .unwrap()
         );
     }
 
     #[test]
-    fn test_part1() {
-        assert_eq!(
-            Platform::load(fs::read_to_string("samples/day14.txt").unwrap())
-                .slide_north()
-                .total_load_north(),
-            136
-        );
-    }
+    fn test_tilts() {
+        let platform = Platform::load(&fixture!("day14").input).unwrap();
+        let tilts = platform.tilts(2);
 
-    #[test]
-    fn test_part2() {
-        assert_eq!(
-            Platform::load(fs::read_to_string("samples/day14.txt").unwrap())
-                .slide_cycle(1_000_000_000)
-                .total_load_north(),
-            64
-        );
+        assert_eq!(8, tilts.len());
+        assert_eq!(platform.slide_north(), tilts[0]);
+        assert_eq!(platform.slide_cycle(1), tilts[3]);
+        assert_eq!(platform.slide_cycle(2), tilts[7]);
     }
 }
@@ -1,58 +1,62 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
 use std::collections::HashMap;
 use std::fmt::Debug;
-use std::fs;
 
 #[derive(Debug)]
 struct Step {
     line: String,
+    operation: Operation,
 }
 
 impl Step {
-    pub fn load(input: &str) -> Self {
-        Self {
+    pub fn load(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
             line: input.to_string(),
-        }
+            operation: Operation::load(input)?,
+        })
     }
 
     pub fn operation(&self) -> Operation {
-        Operation::load(&self.line)
+        self.operation.clone()
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 enum Operation {
     Remove { label: String },
     Insert { label: String, focal_length: u8 },
 }
 
 impl Operation {
-    pub fn load(input: &str) -> Self {
-        match input.find('=') {
-            Some(index) => Self::Insert {
-                label: input[..index].to_string(),
-                focal_length: input.chars().last().unwrap().to_digit(10).unwrap() as u8,
-            },
-            None => Self::Remove {
-                label: input[..input.len() - 1].to_string(),
-            },
+    pub fn load(input: &str) -> Result<Self, ParseError> {
+        match (input.split_once('='), input.strip_suffix('-')) {
+            (Some((label, focal_length)), _) => Ok(Self::Insert {
+                label: label.to_string(),
+                focal_length: parse::token(focal_length, "focal length")?,
+            }),
+            (None, Some(label)) => Ok(Self::Remove {
+                label: label.to_string(),
+            }),
+            (None, None) => Err(ParseError::at(input, "Expected \"=\" or \"-\"")),
         }
     }
 }
 
 #[derive(Debug)]
-struct Sequence {
+pub struct Sequence {
     steps: Vec<Step>,
 }
 
 impl Sequence {
-    pub fn load(contents: String) -> Self {
-        Self {
+    pub fn load(contents: &str) -> Result<Self, ParseError> {
+        Ok(Self {
             steps: contents
                 .trim()
                 .split(',')
-                .map(|line| Step::load(line))
-                .collect(),
-        }
+                .map(Step::load)
+                .collect::<Result<_, _>>()?,
+        })
     }
 
     pub fn hash(&self, input: &str) -> u8 {
@@ -60,14 +64,23 @@ impl Sequence {
             .chars()
             .fold(0, |acc, char| ((acc + char as u16) * 17) % 256) as u8
     }
+}
+
+impl Solution for Sequence {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::load(input)
+    }
 
-    pub fn part1(&self) -> usize {
+    fn part_1(&self) -> usize {
         self.steps
             .iter()
             .fold(0, |acc, step| acc + self.hash(&step.line) as usize)
     }
 
-    pub fn part2(&self) -> usize {
+    fn part_2(&self) -> usize {
         let mut boxes: HashMap<u8, Vec<(String, u8)>> = HashMap::new();
 
         // Install lenses by processing steps
@@ -102,22 +115,14 @@ impl Sequence {
     }
 }
 
-fn main() {
-    let platform = Sequence::load(fs::read_to_string("inputs/day15.txt").unwrap());
-
-    // 514394
-    println!("Part 1: {}", platform.part1());
-
-    println!("Part 2: {}", platform.part2());
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::fixture;
 
     #[test]
-    fn test_part1() {
-        let sequence = Sequence::load(fs::read_to_string("samples/day15.txt").unwrap());
+    fn test_hash() {
+        let sequence = Sequence::load(&fixture!("day15").input).unwrap();
 
         assert_eq!(sequence.hash("HASH"), 52);
         assert_eq!(sequence.hash("rn=1"), 30);
@@ -131,29 +136,22 @@ mod tests {
         assert_eq!(sequence.hash("pc-"), 48);
         assert_eq!(sequence.hash("pc=6"), 214);
         assert_eq!(sequence.hash("ot=7"), 231);
-
-        assert_eq!(sequence.part1(), 1320);
     }
 
     #[test]
-    fn test_part2() {
+    fn test_operation() {
         assert_eq!(
-            Step::load("rn=1").operation(),
+            Step::load("rn=1").unwrap().operation(),
             Operation::Insert {
                 label: "rn".to_string(),
                 focal_length: 1
             }
         );
         assert_eq!(
-            Step::load("cm-").operation(),
+            Step::load("cm-").unwrap().operation(),
             Operation::Remove {
                 label: "cm".to_string(),
             }
         );
-
-        assert_eq!(
-            Sequence::load(fs::read_to_string("samples/day15.txt").unwrap()).part2(),
-            145
-        );
     }
 }
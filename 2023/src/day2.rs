@@ -0,0 +1,124 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
+
+#[derive(Debug)]
+struct CubeGame {
+    id: u32,
+    sets: Vec<CubeSet>,
+}
+
+impl CubeGame {
+    pub fn superset(&self) -> CubeSet {
+        let mut superset = CubeSet { red: 0, green: 0, blue: 0 };
+
+        self.sets.iter().for_each(|set| {
+            if set.red > superset.red {
+                superset.red = set.red;
+            }
+            if set.green > superset.green {
+                superset.green = set.green;
+            }
+            if set.blue > superset.blue {
+                superset.blue = set.blue;
+            }
+        });
+
+        superset
+    }
+}
+
+#[derive(Debug)]
+pub struct CubeSet {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+impl CubeSet {
+    pub fn power(&self) -> u32 {
+        self.red * self.green * self.blue
+    }
+}
+
+#[derive(Debug)]
+pub struct State {
+    games: Vec<CubeGame>,
+}
+
+impl State {
+    pub fn from_string(contents: &str) -> Result<Self, ParseError>
+    {
+        Ok(Self {
+            games: contents.trim().
+                lines()
+                .map(|line| {
+                    let (col_game, col_sets) = parse::split_once(line, ": ")?;
+                    let id = col_game.strip_prefix("Game ")
+                        .ok_or_else(|| ParseError::at(col_game, "Expected \"Game <id>\""))?;
+
+                    Ok(CubeGame {
+                        id: parse::token(id, "game id")?,
+                        sets: col_sets.split("; ")
+                            .map(|col_set| {
+                                let mut set = CubeSet { red: 0, green: 0, blue: 0 };
+
+                                for col_color in col_set.split(", ") {
+                                    let (number, color) = parse::split_once(col_color, " ")?;
+
+                                    match color {
+                                        "red" => set.red = parse::number(number)?,
+                                        "green" => set.green = parse::number(number)?,
+                                        "blue" => set.blue = parse::number(number)?,
+                                        _ => return Err(ParseError::at(color, "Unknown color")),
+                                    }
+                                }
+
+                                Ok(set)
+                            })
+                            .collect::<Result<_, _>>()?,
+                    })
+                })
+                .collect::<Result<_, _>>()?
+        })
+    }
+
+    pub fn sum_possible_game_ids(&self, check_set: CubeSet) -> u32
+    {
+        self.games.iter()
+            .filter(|game| {
+                game.sets.iter()
+                    .filter(|set| {
+                        set.red <= check_set.red &&
+                            set.green <= check_set.green &&
+                            set.blue <= check_set.blue
+                    })
+                    .count() == game.sets.len()
+            })
+            .map(|game| game.id)
+            .sum()
+    }
+
+    pub fn sum_game_powers(&self) -> u32
+    {
+        self.games.iter()
+            .map(|game| game.superset().power())
+            .sum()
+    }
+}
+
+impl Solution for State {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::from_string(input)
+    }
+
+    fn part_1(&self) -> u32 {
+        self.sum_possible_game_ids(CubeSet { red: 12, green: 13, blue: 14 })
+    }
+
+    fn part_2(&self) -> u32 {
+        self.sum_game_powers()
+    }
+}
@@ -1,4 +1,5 @@
-use std::fs;
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
 
 // todo: refactor using part 2 code
 fn pt1_sum(contents: String) -> u32 {
@@ -9,13 +10,13 @@ fn pt1_sum(contents: String) -> u32 {
                 .enumerate()
                 // Only digits with no preceding digit (start of a number)
                 .filter(|(i_char, char)| {
-                    char.is_digit(10) &&
+                    char.is_ascii_digit() &&
                         (i_char == &0usize || !line.as_bytes()[i_char - 1].is_ascii_digit())
                 })
                 // Transform to tuples of numbers with their starting index
                 .map(|(i_char, _)| {
                     (i_char, line[i_char..].chars()
-                        .take_while(|char| char.is_digit(10))
+                        .take_while(|char| char.is_ascii_digit())
                         .collect::<String>()
                         .parse::<u32>()
                         .unwrap())
@@ -29,9 +30,9 @@ fn pt1_sum(contents: String) -> u32 {
                         // Only keep lines that have one or more symbols in surrounding columns
                         .filter(|(_, line)| {
                             line.chars()
-                                .skip(i_number.clone().saturating_sub(1)) // col start
+                                .skip((*i_number).saturating_sub(1)) // col start
                                 .take(number.to_string().len() + 2) // col end
-                                .filter(|char| !char.is_digit(10) && char.clone() != '.')
+                                .filter(|char| !char.is_ascii_digit() && *char != '.')
                                 .count() > 0
                         })
                         // Only keep numbers with neighbouring symbols
@@ -43,48 +44,38 @@ fn pt1_sum(contents: String) -> u32 {
         .sum()
 }
 
-
 #[derive(Debug)]
 struct Schematic {
     parts: Vec<Part>,
 }
 
 impl Schematic {
-    fn load(contents: String) -> Self {
-        Self {
+    fn load(contents: &str) -> Result<Self, ParseError> {
+        Ok(Self {
             parts: contents.lines()
                 .enumerate()
-                .map(|(y, line)| {
+                .flat_map(|(y, line)| {
                     line.chars()
                         .enumerate()
-                        .filter(|(_, char)| char.clone() != '.') // todo: compare without clone?
+                        .filter(|(_, char)| *char != '.')
                         .filter(|(x, char)| {
                             // Only symbols or digits without preceding digits (start of number sequence)
-                            !char.is_digit(10) || x.clone() == 0usize || !line.as_bytes()[x.saturating_sub(1)].is_ascii_digit()
+                            !char.is_ascii_digit() || *x == 0usize || !line.as_bytes()[x.saturating_sub(1)].is_ascii_digit()
                         })
-                        .fold(vec![], |mut parts: Vec<Part>, (x, char)| {
-                            if char.is_digit(10) {
-                                parts.push(Part {
-                                    x,
-                                    y,
-                                    kind: PartKind::Number(
-                                        line[x..].chars()
-                                            .take_while(|char| char.is_digit(10))
-                                            .collect::<String>()
-                                            .parse::<u16>()
-                                            .unwrap()
-                                    ),
-                                })
+                        .map(move |(x, char)| {
+                            if char.is_ascii_digit() {
+                                let digits = line[x..].chars()
+                                    .take_while(|char| char.is_ascii_digit())
+                                    .count();
+
+                                Ok(Part { x, y, kind: PartKind::Number(parse::number(&line[x..x + digits])?) })
                             } else {
-                                parts.push(Part { x, y, kind: PartKind::Symbol(char) });
+                                Ok(Part { x, y, kind: PartKind::Symbol(char) })
                             }
-
-                            parts
                         })
                 })
-                .flatten()
-                .collect()
-        }
+                .collect::<Result<_, _>>()?
+        })
     }
 
     fn neighbour_parts(&self, origin_part: &Part) -> Vec<&Part> {
@@ -95,10 +86,7 @@ impl Schematic {
 
     fn gear_ratio_sum(&self) -> u32 {
         self.parts.iter()
-            .filter(|part| match part.kind {
-                PartKind::Symbol('*') => true,
-                _ => false
-            })
+            .filter(|part| matches!(part.kind, PartKind::Symbol('*')))
             .map(|gear_part| {
                 let neighbour_numbers: Vec<u32> = self.neighbour_parts(gear_part)
                     .iter()
@@ -114,7 +102,7 @@ impl Schematic {
                     return 0;
                 }
 
-                neighbour_numbers.iter().fold(1, |acc, num| acc * num)
+                neighbour_numbers.iter().product::<u32>()
             })
             .sum()
     }
@@ -159,27 +147,27 @@ impl Part {
     }
 }
 
-fn main() {
-    let contents = fs::read_to_string("inputs/day3.txt").expect("Failed to read input");
-
-    println!("Part 1: {}", pt1_sum(contents));
-    println!("Part 2: {}", Schematic::load(fs::read_to_string("inputs/day3.txt").expect("Failed to read input"))
-        .gear_ratio_sum());
+pub struct Puzzle {
+    contents: String,
+    schematic: Schematic,
 }
 
-#[test]
-fn test_part1() {
-    assert_eq!(
-        pt1_sum(fs::read_to_string("samples/day3.txt").expect("Failed to read input")),
-        4361
-    );
-}
+impl Solution for Puzzle {
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-#[test]
-fn test_part2() {
-    assert_eq!(
-        Schematic::load(fs::read_to_string("samples/day3.txt").expect("Failed to read input"))
-            .gear_ratio_sum(),
-        467835
-    );
-}
\ No newline at end of file
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            contents: input.to_string(),
+            schematic: Schematic::load(input)?,
+        })
+    }
+
+    fn part_1(&self) -> u32 {
+        pt1_sum(self.contents.clone())
+    }
+
+    fn part_2(&self) -> u32 {
+        self.schematic.gear_ratio_sum()
+    }
+}
@@ -1,9 +1,10 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
 use std::cmp::max;
 use std::collections::HashMap;
-use std::fs;
 
 #[derive(Debug)]
-struct ScratchCard {
+pub struct ScratchCard {
     card_number: u32,
     win_numbers: Vec<u32>,
     card_numbers: Vec<u32>,
@@ -27,7 +28,7 @@ impl ScratchCard {
 }
 
 #[derive(Debug)]
-struct Puzzle {
+pub struct Puzzle {
     cards: Vec<ScratchCard>,
 }
 
@@ -40,7 +41,7 @@ impl Puzzle {
 
     pub fn count_won_cards(&self, card: &ScratchCard, counts: &mut HashMap<u32, u32>) -> u32 {
         match counts.get(&card.card_number) {
-            Some(count) => count.clone(),
+            Some(count) => *count,
             None => {
                 let won_card_numbers = card.won_card_numbers();
 
@@ -61,43 +62,41 @@ impl Puzzle {
         }
     }
 
-    fn load(contents: String) -> Self {
-        Self {
+    fn load(contents: &str) -> Result<Self, ParseError> {
+        Ok(Self {
             cards: contents
                 .lines()
-                .map(|line| line.split_once(":").unwrap())
-                .map(|(lft, rgt)| {
-                    let (winning_numbers, card_numbers) = rgt.split_once(" | ").unwrap();
-
-                    ScratchCard {
-                        card_number: lft
-                            .to_string()
-                            .split_ascii_whitespace()
-                            .last()
-                            .unwrap()
-                            .parse::<u32>()
-                            .unwrap(),
-                        win_numbers: winning_numbers
-                            .to_string()
-                            .split_ascii_whitespace()
-                            .map(|num| num.parse::<u32>().unwrap())
-                            .collect(),
-                        card_numbers: card_numbers
-                            .to_string()
-                            .split_ascii_whitespace()
-                            .map(|num| num.parse::<u32>().unwrap())
-                            .collect(),
-                    }
+                .map(|line| {
+                    let (lft, rgt) = parse::split_once(line, ":")?;
+                    let (winning_numbers, card_numbers) = parse::split_once(rgt, " | ")?;
+
+                    Ok(ScratchCard {
+                        card_number: parse::token(
+                            lft.strip_prefix("Card").unwrap_or(lft),
+                            "card number",
+                        )?,
+                        win_numbers: parse::numbers(winning_numbers)?,
+                        card_numbers: parse::numbers(card_numbers)?,
+                    })
                 })
-                .collect(),
-        }
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl Solution for Puzzle {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::load(input)
     }
 
-    fn pt1(&self) -> u32 {
+    fn part_1(&self) -> u32 {
         self.cards.iter().map(|card| card.points()).sum()
     }
 
-    fn pt2(&self) -> u32 {
+    fn part_2(&self) -> u32 {
         let mut counts = HashMap::new();
 
         self.cards.len() as u32
@@ -109,12 +108,6 @@ impl Puzzle {
     }
 }
 
-fn main() {
-    let puzzle = Puzzle::load(fs::read_to_string("inputs/day4.txt").expect("Failed to read input"));
-    println!("Part 1: {}", puzzle.pt1());
-    println!("Part 2: {}", puzzle.pt2());
-}
-
 #[test]
 fn test_common() {
     assert_eq!(
@@ -137,19 +130,3 @@ fn test_common() {
         vec![11, 12, 13, 14, 15]
     );
 }
-
-#[test]
-fn test_part1() {
-    assert_eq!(
-        Puzzle::load(fs::read_to_string("samples/day4.txt").expect("Failed to read input")).pt1(),
-        13
-    );
-}
-
-#[test]
-fn test_part2() {
-    assert_eq!(
-        Puzzle::load(fs::read_to_string("samples/day4.txt").expect("Failed to read input")).pt2(),
-        30
-    );
-}
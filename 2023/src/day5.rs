@@ -1,4 +1,5 @@
-use std::fs;
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::{Maybe, Solution};
 
 #[derive(Debug)]
 struct Map {
@@ -41,52 +42,64 @@ impl MapEntry {
 }
 
 #[derive(Debug)]
-struct Almanac {
+pub struct Almanac {
     seeds: Vec<u64>,
     maps: Vec<Map>,
 }
 
 impl Almanac {
-    pub fn load(contents: String) -> Self {
-        let (seeds, maps) = contents.split_once("\n\n").unwrap();
-
-        Self {
-            seeds: seeds[7..]
-                .split_ascii_whitespace()
-                .map(|seed| seed.parse::<u64>().unwrap())
-                .collect(),
+    pub fn load(contents: &str) -> Result<Self, ParseError> {
+        let (seeds, maps) = parse::split_once(contents, "\n\n")?;
+        let seeds = seeds
+            .strip_prefix("seeds:")
+            .ok_or_else(|| ParseError::at(seeds, "Expected \"seeds:\""))?;
+        let seeds = match parse::numbers(seeds)? {
+            // Part 2 reads the seeds as pairs of a start and a length
+            numbers if numbers.is_empty() || numbers.len() % 2 == 1 => {
+                return Err(ParseError::at(seeds, "Expected pairs of seed numbers"))
+            }
+            numbers => numbers,
+        };
+
+        Ok(Self {
+            seeds,
             maps: maps
                 .split("\n\n")
-                .map(|map_section| Map {
-                    _name: map_section.lines().next().unwrap().to_string(),
-                    entries: map_section
-                        .lines()
-                        .skip(1)
-                        .map(|line| {
-                            let nums: Vec<u64> = line
-                                .split_ascii_whitespace()
-                                .map(|num| num.parse::<u64>().unwrap())
-                                .collect();
-
-                            MapEntry {
-                                dest_start: nums[0],
-                                source_start: nums[1],
-                                range: nums[2],
-                            }
-                        })
-                        .collect(),
+                .map(|map_section| {
+                    let mut lines = map_section.lines();
+
+                    Ok(Map {
+                        _name: lines.next().unwrap_or_default().to_string(),
+                        entries: lines
+                            .map(|line| match parse::numbers(line)?[..] {
+                                [dest_start, source_start, range] => Ok(MapEntry {
+                                    dest_start,
+                                    source_start,
+                                    range,
+                                }),
+                                _ => Err(ParseError::at(line, "Expected 3 numbers")),
+                            })
+                            .collect::<Result<_, _>>()?,
+                    })
                 })
-                .collect(),
-        }
+                .collect::<Result<_, _>>()?,
+        })
     }
 
     pub fn to_location(&self, seed: &u64) -> u64 {
-        self.maps
-            .iter()
-            .fold(seed.clone(), |acc, map| map.convert(acc))
+        self.maps.iter().fold(*seed, |acc, map| map.convert(acc))
     }
+}
+
+impl Solution for Almanac {
+    type Answer1 = u64;
+    type Answer2 = Maybe<u64>;
 
-    pub fn pt1(&self) -> u64 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::load(input)
+    }
+
+    fn part_1(&self) -> u64 {
         self.seeds
             .iter()
             .map(|seed| self.to_location(seed))
@@ -94,26 +107,18 @@ impl Almanac {
             .unwrap()
     }
 
-    pub fn pt2(&self) -> u64 {
+    /// No answer when all ranges of seeds are empty
+    fn part_2(&self) -> Maybe<u64> {
         // 1815746760 seeds o_O
         self.seeds
             .chunks(2)
-            .into_iter()
-            .map(|chunk| chunk[0]..chunk[0] + chunk[1])
-            .flatten()
+            .flat_map(|chunk| chunk[0]..chunk[0] + chunk[1])
             .map(|seed| self.to_location(&seed))
             .min()
-            .unwrap()
+            .into()
     }
 }
 
-fn main() {
-    let almanac =
-        Almanac::load(fs::read_to_string("inputs/day5.txt").expect("Failed to read input"));
-    println!("Part 1: {}", almanac.pt1());
-    println!("Part 2: {}", almanac.pt2());
-}
-
 #[test]
 fn test_common() {
     assert_eq!(
@@ -155,21 +160,11 @@ fn test_common() {
 }
 
 #[test]
-fn test_part1() {
-    let almanac =
-        Almanac::load(fs::read_to_string("samples/day5.txt").expect("Failed to read input"));
+fn test_to_location() {
+    let almanac = Almanac::load(&aoc_common::fixture!("day5").input).unwrap();
 
     assert_eq!(almanac.to_location(&79), 82);
     assert_eq!(almanac.to_location(&14), 43);
     assert_eq!(almanac.to_location(&55), 86);
     assert_eq!(almanac.to_location(&13), 35);
-    assert_eq!(almanac.pt1(), 35);
-}
-
-#[test]
-fn test_part2() {
-    let almanac =
-        Almanac::load(fs::read_to_string("samples/day5.txt").expect("Failed to read input"));
-
-    assert_eq!(almanac.pt2(), 46);
 }
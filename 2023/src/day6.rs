@@ -0,0 +1,112 @@
+extern crate core;
+
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
+use std::ops::{Add, Mul, Sub};
+
+struct Race {
+    time: u64,
+    dist: u64,
+}
+
+impl Race {
+    pub fn ways_to_win(&self) -> u64
+    {
+        // Analytic solution:
+        // d=(1/2)(t +- sqrt(t^2-4s)) where d=delay, t=time, s=displacement
+        let disc_sqrt = ((self.time.pow(2) - self.dist.mul(4)) as f64).sqrt();
+
+        // Current record
+        let record_max = (self.time as f64 + disc_sqrt) * 0.5;
+        let record_min = (self.time as f64 - disc_sqrt) * 0.5;
+
+        // Values to break the record
+        let break_min = record_min.add(1.0).floor() as u64;
+        let break_max = record_max.sub(1.0).ceil() as u64;
+
+        break_max - break_min + 1
+    }
+}
+
+struct Puzzle {
+    races: Vec<Race>,
+}
+
+impl Puzzle {
+    pub fn load(contents: &str) -> Result<Self, ParseError> {
+        let (times, dists) = Self::parse_lines(contents, parse::numbers)?;
+
+        Ok(Self {
+            races: times.into_iter().zip(dists).map(|(time, dist)| Race { time, dist }).collect()
+        })
+    }
+
+    /// Part 2 input has bad kerning: all numbers on a line make up one single number
+    pub fn load_pt2(contents: &str) -> Result<Self, ParseError> {
+        let (times, dists) = Self::parse_lines(contents, |numbers| {
+            Ok(vec![numbers.replace(' ', "").parse()
+                .map_err(|_| ParseError::at(numbers.trim(), "Invalid number"))?])
+        })?;
+
+        Ok(Self {
+            races: vec![Race { time: times[0], dist: dists[0] }]
+        })
+    }
+
+    fn parse_lines(
+        contents: &str,
+        parse_numbers: impl Fn(&str) -> Result<Vec<u64>, ParseError>,
+    ) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+        let mut lines = contents
+            .lines()
+            .map(|line| parse_numbers(parse::split_once(line, ":")?.1));
+        let mut next = |name| lines.next().unwrap_or_else(|| Err(ParseError::after(contents, format!("Missing {}", name))));
+        let (times, dists) = (next("times")?, next("distances")?);
+
+        match times.len() == dists.len() {
+            true => Ok((times, dists)),
+            false => Err(ParseError::at(contents, "Expected as many times as distances")),
+        }
+    }
+
+    pub fn ways_to_win(&self) -> u64 {
+        self.races.iter().fold(1, |acc, race| acc * race.ways_to_win())
+    }
+}
+
+pub struct Sheet {
+    pt1: Puzzle,
+    pt2: Puzzle,
+}
+
+impl Solution for Sheet {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            pt1: Puzzle::load(input)?,
+            pt2: Puzzle::load_pt2(input)?,
+        })
+    }
+
+    fn part_1(&self) -> u64 {
+        self.pt1.ways_to_win()
+    }
+
+    fn part_2(&self) -> u64 {
+        self.pt2.ways_to_win()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ways_to_win() {
+        assert_eq!(Race { time: 7, dist: 9 }.ways_to_win(), 4);
+        assert_eq!(Race { time: 15, dist: 40 }.ways_to_win(), 8);
+        assert_eq!(Race { time: 30, dist: 200 }.ways_to_win(), 9);
+    }
+}
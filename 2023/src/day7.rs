@@ -1,8 +1,9 @@
 extern crate core;
 
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::fs;
 use std::iter::zip;
 
 #[derive(Debug)]
@@ -12,8 +13,8 @@ struct Card {
 }
 
 impl Card {
-    pub fn new(label: char) -> Self {
-        Self {
+    pub fn new(label: char) -> Option<Self> {
+        Some(Self {
             label,
             strength: match label {
                 'A' => 14,
@@ -22,9 +23,9 @@ impl Card {
                 'J' => 11,
                 'T' => 10,
                 '*' => 1, // * = joker
-                _ => label.to_digit(10).unwrap(),
+                _ => label.to_digit(10)?,
             },
-        }
+        })
     }
 }
 
@@ -74,18 +75,21 @@ struct Hand {
 }
 
 impl Hand {
-    pub fn from_string(string: String) -> Self {
-        let (col_hand, col_bid) = string.split_once(' ').unwrap();
-        Self {
+    pub fn from_string(string: &str) -> Result<Self, ParseError> {
+        let (col_hand, col_bid) = parse::split_once(string, " ")?;
+        Ok(Self {
             cards: col_hand
-                .chars()
-                .map(|card_label| Card::new(card_label))
-                .collect(),
-            bid: col_bid.parse::<u32>().unwrap(),
-        }
+                .char_indices()
+                .map(|(i, label)| {
+                    Card::new(label)
+                        .ok_or_else(|| ParseError::at(parse::char_at(col_hand, i), "Invalid card"))
+                })
+                .collect::<Result<_, _>>()?,
+            bid: parse::token(col_bid, "bid")?,
+        })
     }
 
-    pub fn identify(&self) -> IdentifiedHand {
+    pub fn identify(&self) -> IdentifiedHand<'_> {
         let mut label_freqs: HashMap<char, u32> =
             self.cards.iter().fold(HashMap::new(), |mut freqs, card| {
                 match freqs.get(&card.label) {
@@ -95,7 +99,7 @@ impl Hand {
                 freqs
             });
 
-        let joker_count = label_freqs.get(&'*').unwrap_or(&0u32).clone();
+        let joker_count = *label_freqs.get(&'*').unwrap_or(&0u32);
         label_freqs.remove(&'*');
 
         IdentifiedHand {
@@ -139,17 +143,17 @@ struct Puzzle {
 }
 
 impl Puzzle {
-    pub fn load(contents: String) -> Self {
-        Self {
+    pub fn load(contents: &str) -> Result<Self, ParseError> {
+        Ok(Self {
             hands: contents
                 .lines()
-                .map(|line| Hand::from_string(line.to_string()))
-                .collect(),
-        }
+                .map(Hand::from_string)
+                .collect::<Result<_, _>>()?,
+        })
     }
 
-    pub fn load_pt2(contents: String) -> Self {
-        Self::load(contents.replace("J", "*"))
+    pub fn load_pt2(contents: &str) -> Result<Self, ParseError> {
+        Self::load(&contents.replace("J", "*"))
     }
 
     pub fn total_winnings(&self) -> u32 {
@@ -171,16 +175,29 @@ impl Puzzle {
     }
 }
 
-fn main() {
-    println!(
-        "Part 1: {}",
-        Puzzle::load(fs::read_to_string("inputs/day7.txt").unwrap()).total_winnings()
-    );
-    // Attempts: 249591015 -> 250892960 -> 249631254
-    println!(
-        "Part 2: {}",
-        Puzzle::load_pt2(fs::read_to_string("inputs/day7.txt").unwrap()).total_winnings()
-    );
+pub struct Game {
+    pt1: Puzzle,
+    pt2: Puzzle,
+}
+
+impl Solution for Game {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            pt1: Puzzle::load(input)?,
+            pt2: Puzzle::load_pt2(input)?,
+        })
+    }
+
+    fn part_1(&self) -> u32 {
+        self.pt1.total_winnings()
+    }
+
+    fn part_2(&self) -> u32 {
+        self.pt2.total_winnings()
+    }
 }
 
 #[cfg(test)]
@@ -193,59 +210,45 @@ mod tests {
     #[test]
     fn test_common() {
         assert_eq!(
-            Hand::from_string("KKKKK 123".to_string())
-                .identify()
-                .hand_type,
+            Hand::from_string("KKKKK 123").unwrap().identify().hand_type,
             HandType::FiveOfAKind
         );
         assert_eq!(
-            Hand::from_string("KKKKA 123".to_string())
-                .identify()
-                .hand_type,
+            Hand::from_string("KKKKA 123").unwrap().identify().hand_type,
             HandType::FourOfAKind
         );
         assert_eq!(
-            Hand::from_string("KKKAA 123".to_string())
-                .identify()
-                .hand_type,
+            Hand::from_string("KKKAA 123").unwrap().identify().hand_type,
             HandType::FullHouse
         );
         assert_eq!(
-            Hand::from_string("KKKAQ 123".to_string())
-                .identify()
-                .hand_type,
+            Hand::from_string("KKKAQ 123").unwrap().identify().hand_type,
             HandType::ThreeOfAKind
         );
         assert_eq!(
-            Hand::from_string("KTJJT 123".to_string())
-                .identify()
-                .hand_type,
+            Hand::from_string("KTJJT 123").unwrap().identify().hand_type,
             HandType::TwoPair
         );
         assert_eq!(
-            Hand::from_string("KKAJQ 123".to_string())
-                .identify()
-                .hand_type,
+            Hand::from_string("KKAJQ 123").unwrap().identify().hand_type,
             HandType::OnePair
         );
         assert_eq!(
-            Hand::from_string("KTAJQ 123".to_string())
-                .identify()
-                .hand_type,
+            Hand::from_string("KTAJQ 123").unwrap().identify().hand_type,
             HandType::HighCard
         );
 
         // Compare strengths for cards with equal types
         let hand_1 = IdentifiedHand {
-            hand: &Hand::from_string("22222 123".to_string()),
+            hand: &Hand::from_string("22222 123").unwrap(),
             hand_type: HandType::HighCard,
         };
         let hand_2 = IdentifiedHand {
-            hand: &Hand::from_string("11111 123".to_string()),
+            hand: &Hand::from_string("11111 123").unwrap(),
             hand_type: HandType::HighCard,
         };
         let hand_3 = IdentifiedHand {
-            hand: &Hand::from_string("33333 123".to_string()),
+            hand: &Hand::from_string("33333 123").unwrap(),
             hand_type: HandType::HighCard,
         };
         assert_eq!(hand_1.compare(&hand_1), Ordering::Equal);
@@ -255,11 +258,11 @@ mod tests {
         // Specific case from sample (2x two pairs -> compare strengths)
         assert_eq!(
             IdentifiedHand {
-                hand: &Hand::from_string("KK677 28".to_string()),
+                hand: &Hand::from_string("KK677 28").unwrap(),
                 hand_type: HandType::HighCard,
             }
             .compare(&IdentifiedHand {
-                hand: &Hand::from_string("KTJJT 220".to_string()),
+                hand: &Hand::from_string("KTJJT 220").unwrap(),
                 hand_type: HandType::HighCard,
             }),
             Ordering::Greater
@@ -267,11 +270,11 @@ mod tests {
 
         assert_eq!(
             IdentifiedHand {
-                hand: &Hand::from_string("JJJJJ 666".to_string()),
+                hand: &Hand::from_string("JJJJJ 666").unwrap(),
                 hand_type: HandType::FiveOfAKind,
             }
             .compare(&IdentifiedHand {
-                hand: &Hand::from_string("99979 459".to_string()),
+                hand: &Hand::from_string("99979 459").unwrap(),
                 hand_type: HandType::FourOfAKind,
             }),
             Ordering::Greater
@@ -279,11 +282,11 @@ mod tests {
 
         assert_eq!(
             IdentifiedHand {
-                hand: &Hand::from_string("99959 922".to_string()),
+                hand: &Hand::from_string("99959 922").unwrap(),
                 hand_type: HandType::FourOfAKind,
             }
             .compare(&IdentifiedHand {
-                hand: &Hand::from_string("99899 277".to_string()),
+                hand: &Hand::from_string("99899 277").unwrap(),
                 hand_type: HandType::FourOfAKind,
             }),
             Ordering::Greater
@@ -291,11 +294,11 @@ mod tests {
 
         assert_eq!(
             IdentifiedHand {
-                hand: &Hand::from_string("99J99 377".to_string()),
+                hand: &Hand::from_string("99J99 377").unwrap(),
                 hand_type: HandType::FourOfAKind,
             }
             .compare(&IdentifiedHand {
-                hand: &Hand::from_string("99899 288".to_string()),
+                hand: &Hand::from_string("99899 288").unwrap(),
                 hand_type: HandType::FourOfAKind,
             }),
             Ordering::Greater
@@ -305,64 +308,36 @@ mod tests {
     #[test]
     fn test_joker() {
         assert_eq!(
-            Hand::from_string("*KKKK 123".to_string())
-                .identify()
-                .hand_type,
+            Hand::from_string("*KKKK 123").unwrap().identify().hand_type,
             HandType::FiveOfAKind
         );
         assert_eq!(
-            Hand::from_string("1*KKK 123".to_string())
-                .identify()
-                .hand_type,
+            Hand::from_string("1*KKK 123").unwrap().identify().hand_type,
             HandType::FourOfAKind
         );
         assert_eq!(
-            Hand::from_string("11*KK 123".to_string())
-                .identify()
-                .hand_type,
+            Hand::from_string("11*KK 123").unwrap().identify().hand_type,
             HandType::FullHouse
         );
         assert_eq!(
-            Hand::from_string("12*KK 123".to_string())
-                .identify()
-                .hand_type,
+            Hand::from_string("12*KK 123").unwrap().identify().hand_type,
             HandType::ThreeOfAKind
         );
         assert_eq!(
-            Hand::from_string("12**K 123".to_string())
-                .identify()
-                .hand_type,
+            Hand::from_string("12**K 123").unwrap().identify().hand_type,
             HandType::ThreeOfAKind
         );
         assert_eq!(
-            Hand::from_string("12*** 123".to_string())
-                .identify()
-                .hand_type,
+            Hand::from_string("12*** 123").unwrap().identify().hand_type,
             HandType::FourOfAKind
         );
         assert_eq!(
-            Hand::from_string("1**** 123".to_string())
-                .identify()
-                .hand_type,
+            Hand::from_string("1**** 123").unwrap().identify().hand_type,
             HandType::FiveOfAKind
         );
         assert_eq!(
-            Hand::from_string("***** 123".to_string())
-                .identify()
-                .hand_type,
+            Hand::from_string("***** 123").unwrap().identify().hand_type,
             HandType::FiveOfAKind
         );
     }
-
-    #[test]
-    fn test_part1() {
-        let puzzle = Puzzle::load(fs::read_to_string("samples/day7.txt").unwrap());
-        assert_eq!(puzzle.total_winnings(), 6440);
-    }
-
-    #[test]
-    fn test_part2() {
-        let puzzle = Puzzle::load_pt2(fs::read_to_string("samples/day7.txt").unwrap());
-        assert_eq!(puzzle.total_winnings(), 5905);
-    }
 }
@@ -0,0 +1,166 @@
+use aoc_common::combinator::{lines, separated_pair, terminated, word, Parser};
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::{Maybe, Solution};
+use num::integer::lcm;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct Map {
+    directions: Vec<Direction>,
+    nodes: Vec<Node>,
+}
+
+impl Map {
+    pub fn load(contents: &str) -> Result<Self, ParseError> {
+        // Lines like `AAA = (BBB, CCC)`
+        let node = separated_pair(
+            word(),
+            " = (",
+            terminated(separated_pair(word(), ", ", word()), ")"),
+        );
+        let (directions, nodes) =
+            separated_pair(word(), "\n\n", lines(node)).parse_all(contents)?;
+
+        let labels = nodes
+            .iter()
+            .map(|&(label, _)| label)
+            .collect::<HashSet<_>>();
+        if let Some(&label) = nodes
+            .iter()
+            .flat_map(|(_, (left, right))| [left, right])
+            .find(|label| !labels.contains(*label))
+        {
+            return Err(ParseError::at(label, "Unknown node"));
+        }
+
+        Ok(Self {
+            directions: directions
+                .char_indices()
+                .map(|(i, char)| match char {
+                    'L' => Ok(Direction::Left),
+                    'R' => Ok(Direction::Right),
+                    _ => Err(ParseError::at(
+                        parse::char_at(directions, i),
+                        "Invalid direction",
+                    )),
+                })
+                .collect::<Result<_, _>>()?,
+            nodes: nodes
+                .into_iter()
+                .map(|(label, (left, right))| Node {
+                    label: label.to_string(),
+                    left: left.to_string(),
+                    right: right.to_string(),
+                })
+                .collect(),
+        })
+    }
+
+    pub fn node(&self, label: &String) -> Option<&Node> {
+        self.nodes.iter().find(|node| node.label.eq(label))
+    }
+
+    pub fn next_node(&self, node: &Node, direction: &Direction) -> &Node {
+        self.node(node.direction_label(direction)).unwrap()
+    }
+
+    pub fn apply_directions<'a>(&'a self, start: &'a Node) -> &'a Node {
+        self.directions
+            .iter()
+            .fold(start, |node, direction| self.next_node(node, direction))
+    }
+
+    pub fn steps_pt1(&self, node: &Node) -> u64 {
+        let mut iterations = 1;
+        let mut current = node;
+
+        loop {
+            current = self.apply_directions(current);
+
+            if current.label == "ZZZ" {
+                break;
+            }
+
+            iterations += 1;
+        }
+
+        iterations * self.directions.len() as u64
+    }
+
+    pub fn steps_pt2(&self, node: &Node) -> u64 {
+        let mut steps = 0;
+        let mut current = node;
+
+        loop {
+            match self
+                .directions
+                .iter()
+                .enumerate()
+                .find_map(|(i, direction)| {
+                    current = self.next_node(current, direction);
+
+                    if current.label.ends_with("Z") {
+                        return Some(i);
+                    }
+
+                    None
+                }) {
+                None => steps += self.directions.len(),
+                Some(i) => {
+                    steps += i + 1;
+                    break;
+                }
+            }
+        }
+
+        steps as u64
+    }
+}
+
+impl Solution for Map {
+    type Answer1 = Maybe<u64>;
+    type Answer2 = Maybe<u64>;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::load(input)
+    }
+
+    /// No answer without the AAA and ZZZ nodes, like for the ghosts' sample
+    fn part_1(&self) -> Maybe<u64> {
+        self.node(&"ZZZ".to_string())
+            .and(self.node(&"AAA".to_string()))
+            .map(|node| self.steps_pt1(node))
+            .into()
+    }
+
+    fn part_2(&self) -> Maybe<u64> {
+        self.nodes
+            .iter()
+            .filter(|node| node.label.ends_with("A"))
+            .map(|node| self.steps_pt2(node))
+            .reduce(lcm)
+            .into()
+    }
+}
+
+#[derive(Debug)]
+pub struct Node {
+    label: String,
+    left: String,
+    right: String,
+}
+
+impl Node {
+    pub fn direction_label(&self, direction: &Direction) -> &String {
+        match direction {
+            Direction::Left => &self.left,
+            _ => &self.right,
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use std::fs;
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
 
 #[derive(Debug, Clone)]
 struct Sequence {
@@ -44,44 +45,49 @@ impl Sequence {
         let first_numbers = self
             .increments_stack()
             .iter()
-            .map(|increments| increments.numbers.first().unwrap().clone())
+            .map(|increments| *increments.numbers.first().unwrap())
             .collect::<Vec<i64>>();
 
-        self.numbers.first().unwrap()
-            - first_numbers[0..first_numbers.len() - 1]
-                .iter()
-                .rfold(first_numbers.last().unwrap().clone(), |acc, num| num - acc)
+        // Constant sequences have no increments that aren't all zero
+        self.numbers.first().unwrap() - first_numbers.iter().rfold(0, |acc, num| num - acc)
     }
 }
 
 #[derive(Debug)]
-struct Report {
+pub struct Report {
     sequences: Vec<Sequence>,
 }
 
 impl Report {
-    pub fn load(contents: String) -> Self {
-        Report {
+    pub fn load(contents: &str) -> Result<Self, ParseError> {
+        Ok(Report {
             sequences: contents
                 .lines()
-                .map(|line| Sequence {
-                    numbers: line
-                        .split_whitespace()
-                        .map(|number| number.parse::<i64>().expect("Invalid number detected"))
-                        .collect::<Vec<i64>>(),
+                .map(|line| match parse::numbers(line)? {
+                    numbers if numbers.is_empty() => Err(ParseError::at(line, "Empty sequence")),
+                    numbers => Ok(Sequence { numbers }),
                 })
-                .collect(),
-        }
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl Solution for Report {
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::load(input)
     }
 
-    pub fn part1(&self) -> i64 {
+    fn part_1(&self) -> i64 {
         self.sequences
             .iter()
             .map(|sequence| sequence.next_num())
             .sum()
     }
 
-    pub fn part2(&self) -> i64 {
+    fn part_2(&self) -> i64 {
         self.sequences
             .iter()
             .map(|sequence| sequence.prev_num())
@@ -89,17 +95,6 @@ impl Report {
     }
 }
 
-fn main() {
-    println!(
-        "Part 1: {}",
-        Report::load(fs::read_to_string("inputs/day9.txt").unwrap()).part1()
-    );
-    println!(
-        "Part 2: {}",
-        Report::load(fs::read_to_string("inputs/day9.txt").unwrap()).part2()
-    );
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,21 +159,12 @@ mod tests {
             .prev_num(),
             5
         );
-    }
-
-    #[test]
-    fn test_part1() {
-        assert_eq!(
-            Report::load(fs::read_to_string("samples/day9.txt").unwrap()).part1(),
-            114
-        );
-    }
-
-    #[test]
-    fn test_part2() {
         assert_eq!(
-            Report::load(fs::read_to_string("samples/day9.txt").unwrap()).part2(),
-            2
+            Sequence {
+                numbers: vec![7, 7, 7]
+            }
+            .prev_num(),
+            7
         );
     }
 }
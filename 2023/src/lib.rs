@@ -0,0 +1,46 @@
+mod day1;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+
+use aoc_common::puzzle::{Day, Year};
+
+pub const YEAR: Year = Year {
+    year: 2023,
+    input_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    answers_path: concat!(env!("CARGO_MANIFEST_DIR"), "/answers.txt"),
+    learnings_path: concat!(env!("CARGO_MANIFEST_DIR"), "/learnings.txt"),
+    days: &[
+        Day::new::<day1::Document>(1),
+        Day::new::<day2::State>(2),
+        Day::new::<day3::Puzzle>(3),
+        Day::new::<day4::Puzzle>(4),
+        Day::new::<day5::Almanac>(5),
+        Day::new::<day6::Sheet>(6),
+        Day::new::<day7::Game>(7),
+        Day::new::<day8::Map>(8),
+        Day::new::<day9::Report>(9),
+        Day::new::<day10::Map>(10),
+        Day::new::<day11::Universe>(11),
+        Day::new::<day12::Puzzle>(12),
+        Day::new::<day13::Puzzle>(13),
+        Day::new::<day14::Platform>(14),
+        Day::new::<day15::Sequence>(15),
+    ],
+};
+
+#[cfg(test)]
+mod fixtures {
+    include!(concat!(env!("OUT_DIR"), "/fixture_tests.rs"));
+}
@@ -0,0 +1,52 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
+
+pub struct Problem {
+    left: Vec<u32>,
+    right: Vec<u32>,
+}
+
+impl Problem {
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        let (left, right) = string
+            .lines()
+            .map(|line| match parse::numbers::<u32>(line)?[..] {
+                [left, right] => Ok((left, right)),
+                _ => Err(ParseError::at(line, "Expected two location IDs")),
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+
+        Ok(Self { left, right })
+    }
+}
+
+impl Solution for Problem {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::from_string(input)
+    }
+
+    fn part_1(&self) -> u32 {
+        let mut left = self.left.clone();
+        let mut right = self.right.clone();
+
+        left.sort();
+        right.sort();
+
+        left.iter()
+            .zip(right.iter())
+            .map(|(&a, &b)| a.abs_diff(b))
+            .sum()
+    }
+
+    fn part_2(&self) -> u32 {
+        self.left
+            .iter()
+            .map(|&left| left * self.right.iter().filter(|&&right| left == right).count() as u32)
+            .sum()
+    }
+}
@@ -0,0 +1,198 @@
+use aoc_common::geometry::{Direction, Vec2};
+use aoc_common::grid::Grid;
+use aoc_common::parse::ParseError;
+use aoc_common::puzzle::Solution;
+use std::collections::{HashMap, HashSet};
+
+struct Directions {
+    steps: HashMap<Vec2, HashSet<Vec2>>,
+}
+
+impl Directions {
+    fn count_paths(&self, from: Vec2, to: Vec2) -> usize {
+        match self.steps.get(&from) {
+            None => 0,
+            Some(next_positions) => {
+                if next_positions.contains(&to) {
+                    1
+                } else {
+                    next_positions
+                        .iter()
+                        .map(|&next_pos| self.count_paths(next_pos, to))
+                        .sum()
+                }
+            }
+        }
+    }
+
+    fn leads_to(&self, goal: Vec2) -> bool {
+        self.steps
+            .iter()
+            .find(|(_, to)| to.contains(&goal))
+            .is_some()
+    }
+}
+
+#[derive(Debug)]
+struct Map {
+    width: usize,
+    height: usize,
+    heights: Vec<u8>,
+}
+
+impl Map {
+    fn from_string(string: &str) -> Result<Map, ParseError> {
+        let grid = Grid::parse_with(string, |char| match char {
+            '.' => Ok(127), // impassable tiles in the samples
+            _ => char
+                .to_digit(10)
+                .map(|height| height as u8)
+                .ok_or("Invalid height"),
+        })?;
+
+        Ok(Self {
+            width: grid.width(),
+            height: grid.height(),
+            heights: grid.rows().flatten().copied().collect(),
+        })
+    }
+
+    fn height_at(&self, pos: Vec2) -> Option<u8> {
+        pos.to_position()
+            .filter(|&(x, y)| x < self.width && y < self.height)
+            .map(|(x, y)| self.heights[x + y * self.width])
+    }
+
+    fn positions_of_height(&self, of_height: u8) -> Vec<Vec2> {
+        self.heights
+            .iter()
+            .enumerate()
+            .filter(|(_, height)| **height == of_height)
+            .map(|(i, _)| Vec2::new((i % self.width) as i64, (i / self.width) as i64))
+            .collect()
+    }
+
+    fn trailheads(&self) -> Vec<Vec2> {
+        self.positions_of_height(0)
+    }
+
+    fn peaks(&self) -> Vec<Vec2> {
+        self.positions_of_height(9)
+    }
+
+    fn neighbours_of(&self, pos: Vec2) -> Vec<Vec2> {
+        [
+            Direction::Left,
+            Direction::Right,
+            Direction::Up,
+            Direction::Down,
+        ]
+        .into_iter()
+        .map(|direction| pos + direction.vec())
+        .filter(|&neighbour| self.height_at(neighbour).is_some())
+        .collect()
+    }
+
+    fn directions_from(&self, from: Vec2) -> Directions {
+        let mut steps = HashMap::new();
+        let mut frontier = vec![from];
+        while let Some(current_pos) = frontier.pop() {
+            let height = self.height_at(current_pos).unwrap();
+            self.neighbours_of(current_pos)
+                .iter()
+                .filter(|&&pos| self.height_at(pos) == Some(height + 1)) // Only heights one higher than current
+                .for_each(|neighbour_pos| {
+                    frontier.push(*neighbour_pos);
+                    steps
+                        .entry(current_pos)
+                        .or_insert(HashSet::new())
+                        .insert(*neighbour_pos);
+                });
+        }
+
+        Directions { steps }
+    }
+}
+
+#[derive(Debug)]
+pub struct Problem {
+    map: Map,
+}
+
+impl Problem {
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            map: Map::from_string(string)?,
+        })
+    }
+}
+
+impl Solution for Problem {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::from_string(input)
+    }
+
+    fn part_1(&self) -> usize {
+        let peaks = self.map.peaks();
+
+        self.map
+            .trailheads()
+            .iter()
+            .map(|trailhead_pos| {
+                let trails = self.map.directions_from(*trailhead_pos);
+                // For each trailhead check if directions to this peak are available (= reachable)
+                peaks
+                    .iter()
+                    .filter(|peak_pos| trails.leads_to(**peak_pos))
+                    .count()
+            })
+            .sum()
+    }
+
+    fn part_2(&self) -> usize {
+        let peaks = self.map.peaks();
+
+        self.map
+            .trailheads()
+            .iter()
+            .map(|trailhead_pos| {
+                let trails = self.map.directions_from(*trailhead_pos);
+                peaks
+                    .iter()
+                    .map(|peak_pos| trails.count_paths(*trailhead_pos, *peak_pos))
+                    .sum::<usize>()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixture;
+
+    #[test]
+    fn test_map_neighbours() {
+        let map = Map::from_string(&fixture!("day10").input).unwrap();
+        assert_eq!(
+            vec![Vec2::new(1, 0), Vec2::new(0, 1)],
+            map.neighbours_of(Vec2::new(0, 0))
+        );
+        assert_eq!(
+            vec![
+                Vec2::new(0, 1),
+                Vec2::new(2, 1),
+                Vec2::new(1, 0),
+                Vec2::new(1, 2)
+            ],
+            map.neighbours_of(Vec2::new(1, 1))
+        );
+        assert_eq!(
+            vec![Vec2::new(6, 7), Vec2::new(7, 6)],
+            map.neighbours_of(Vec2::new(7, 7))
+        );
+    }
+}
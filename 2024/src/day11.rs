@@ -1,5 +1,6 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
 use std::collections::HashMap;
-use std::fs;
 
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
 struct Stone {
@@ -18,7 +19,7 @@ impl Stone {
 
         let string = self.number.to_string();
 
-        if string.len() % 2 == 0 {
+        if string.len().is_multiple_of(2) {
             let split_at = string.len() / 2;
             return Stones::from_slice(&[
                 Self::new(string[0..split_at].parse().unwrap()),
@@ -36,13 +37,13 @@ struct Stones {
 }
 
 impl Stones {
-    fn from_string(string: &str) -> Self {
-        Self {
-            elements: string
-                .split_whitespace()
-                .map(|stone| Stone::new(stone.parse().unwrap()))
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            elements: parse::numbers(string)?
+                .into_iter()
+                .map(Stone::new)
                 .collect(),
-        }
+        })
     }
 
     fn from_slice(list: &[Stone]) -> Self {
@@ -59,12 +60,12 @@ impl Stones {
     fn blink_count_cached(
         &self,
         blink_times: u32,
-        mut cache: &mut HashMap<Stone, HashMap<u32, usize>>,
+        cache: &mut HashMap<Stone, HashMap<u32, usize>>,
     ) -> usize {
         self.elements
             .iter()
             .map(|&stone| {
-                let cached_stone_counts = cache.entry(stone).or_insert(HashMap::new());
+                let cached_stone_counts = cache.entry(stone).or_default();
 
                 // Check if this stone has been blinked before this many times, if so re-use count
                 if let Some(&cached_count) = cached_stone_counts.get(&blink_times) {
@@ -76,7 +77,7 @@ impl Stones {
 
                 // Count and cache the number of stones after blinking (recursive case + special case)
                 if blink_times > 1 {
-                    let count = blink_stones.blink_count_cached(blink_times - 1, &mut cache);
+                    let count = blink_stones.blink_count_cached(blink_times - 1, cache);
                     cache.get_mut(&stone).unwrap().insert(blink_times, count);
                     count
                 } else {
@@ -90,15 +91,24 @@ impl Stones {
 }
 
 #[derive(Debug)]
-struct Problem {
+pub struct Problem {
     stones: Stones,
 }
 
 impl Problem {
-    fn from_string(string: &str) -> Self {
-        Self {
-            stones: Stones::from_string(string),
-        }
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            stones: Stones::from_string(string)?,
+        })
+    }
+}
+
+impl Solution for Problem {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::from_string(input)
     }
 
     fn part_1(&self) -> usize {
@@ -109,26 +119,3 @@ impl Problem {
         self.stones.blink_count(75)
     }
 }
-
-fn main() {
-    let problem = Problem::from_string(
-        fs::read_to_string("input/day11.txt")
-            .expect("Failed to read input")
-            .as_str(),
-    );
-
-    println!("Part 1: {}", problem.part_1()); // Attempts: 216996
-    println!("Part 2: {}", problem.part_2()); // Attempts: 14090595 (too low), 257335372288947
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE: &str = r#"125 17"#;
-
-    #[test]
-    fn test_sample_part_1() {
-        assert_eq!(55312, Problem::from_string(SAMPLE).part_1());
-    }
-}
@@ -1,24 +1,7 @@
+use aoc_common::geometry::Vec2;
+use aoc_common::parse::ParseError;
+use aoc_common::puzzle::Solution;
 use std::collections::{HashMap, HashSet};
-use std::fs;
-
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
-struct Vec2 {
-    x: i32,
-    y: i32,
-}
-
-impl Vec2 {
-    fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
-    }
-
-    fn is_adjacent(&self, other: Vec2) -> bool {
-        let dx = self.x.abs_diff(other.x);
-        let dy = self.y.abs_diff(other.y);
-
-        dx == 0 && dy == 1 || dx == 1 && dy == 0
-    }
-}
 
 #[derive(Debug, Clone)]
 struct Region {
@@ -43,7 +26,7 @@ impl Region {
                 self.positions
                     .iter()
                     .skip(index + 1)
-                    .filter(|&&other| pos != other && pos.is_adjacent(other))
+                    .filter(|&&other| pos.manhattan(other) == 1)
                     .count() as u32
             })
             .sum();
@@ -75,24 +58,18 @@ impl Region {
                     acc
                 },
             )
-            .iter()
-            .map(|(_, plants)| match plants.len() {
+            .values()
+            .map(|plants| match plants.len() {
                 // Count corners shared with only one plant or by three plants 1, these respectively
                 // represent the outside and inside corners of the region.
                 1 | 3 => 1,
                 // Corners shared by two plants should be counted twice as an outside corner if
                 // those plants are diagonal to each other (this is the special case of diagonal
                 // regions that was mentioned in the instructions).
-                2 => {
-                    if plants[0].x.abs_diff(plants[1].x) == 1
-                        && plants[0].y.abs_diff(plants[1].y) == 1
-                    {
-                        2
-                    } else {
-                        0
-                    }
+                2 if plants[0].chebyshev(plants[1]) == 1 && plants[0].manhattan(plants[1]) == 2 => {
+                    2
                 }
-                4 | _ => 0,
+                _ => 0,
             })
             .sum()
     }
@@ -112,8 +89,7 @@ impl Region {
     fn is_adjacent_pos(&self, pos: Vec2) -> bool {
         self.positions
             .iter()
-            .find(|plant_pos| plant_pos.is_adjacent(pos))
-            .is_some()
+            .any(|plant_pos| plant_pos.manhattan(pos) == 1)
     }
 }
 
@@ -138,8 +114,8 @@ impl Plot {
                 .enumerate()
                 .fold(Vec::new(), |mut regions, (index, &plant)| {
                     let pos = Vec2::new(
-                        (index as u32 % self.width) as i32,
-                        (index as u32 / self.width) as i32,
+                        (index as u32 % self.width) as i64,
+                        (index as u32 / self.width) as i64,
                     );
 
                     let adjacent_regions: Vec<(usize, Region)> = regions
@@ -149,7 +125,7 @@ impl Plot {
                         .map(|(index, region)| (index, region.clone()))
                         .collect();
 
-                    let mut region = if adjacent_regions.len() == 0 {
+                    let mut region = if adjacent_regions.is_empty() {
                         Region::new(plant)
                     } else {
                         // Multiple adjacent regions to join, so the plant is effectively connecting two
@@ -179,7 +155,7 @@ impl Plot {
 }
 
 #[derive(Debug)]
-struct Problem {
+pub struct Problem {
     plot: Plot,
 }
 
@@ -189,6 +165,15 @@ impl Problem {
             plot: Plot::from_string(string),
         }
     }
+}
+
+impl Solution for Problem {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self::from_string(input))
+    }
 
     fn part_1(&self) -> u32 {
         self.plot
@@ -207,71 +192,10 @@ impl Problem {
     }
 }
 
-fn main() {
-    let problem = Problem::from_string(
-        fs::read_to_string("input/day12.txt")
-            .expect("Failed to read input")
-            .as_str(),
-    );
-
-    println!("Part 1: {}", problem.part_1()); // Attempts: 1449902
-    println!("Part 2: {}", problem.part_2()); // Attempts: 908042
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const SAMPLE_SIMPLIFIED_1: &str = r#"AAAA
-BBCD
-BBCC
-EEEC"#;
-
-    const SAMPLE_SIMPLIFIED2: &str = r#"OOOOO
-OXOXO
-OOOOO
-OXOXO
-OOOOO"#;
-
-    const SAMPLE: &str = r#"RRRRIICCFF
-RRRRIICCCF
-VVRRRCCFFF
-VVRCCCJFFF
-VVVVCJJCFE
-VVIVCCJJEE
-VVIIICJJEE
-MIIIIIJJEE
-MIIISIJEEE
-MMMISSJEEE"#;
-
-    const SAMPLE_SIMPLIFIED3: &str = r#"EEEEE
-EXXXX
-EEEEE
-EXXXX
-EEEEE"#;
-
-    const SAMPLE_SIMPLIFIED4: &str = r#"AAAAAA
-AAABBA
-AAABBA
-ABBAAA
-ABBAAA
-AAAAAA"#;
-
-    #[test]
-    fn test_sample_part_1() {
-        assert_eq!(140, Problem::from_string(SAMPLE_SIMPLIFIED_1).part_1());
-        assert_eq!(772, Problem::from_string(SAMPLE_SIMPLIFIED2).part_1());
-        assert_eq!(1930, Problem::from_string(SAMPLE).part_1());
-    }
-    #[test]
-    fn test_sample_part_2() {
-        assert_eq!(80, Problem::from_string(SAMPLE_SIMPLIFIED_1).part_2());
-        assert_eq!(436, Problem::from_string(SAMPLE_SIMPLIFIED2).part_2());
-        assert_eq!(236, Problem::from_string(SAMPLE_SIMPLIFIED3).part_2());
-        assert_eq!(368, Problem::from_string(SAMPLE_SIMPLIFIED4).part_2());
-        assert_eq!(1206, Problem::from_string(SAMPLE).part_2());
-    }
-
     #[test]
     fn test_region_sides() {
         assert_eq!(4, Plot::from_string("AAA").regions()[0].sides());
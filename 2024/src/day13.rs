@@ -0,0 +1,124 @@
+use aoc_common::combinator::{
+    integer, pair, preceded, sections, separated_pair, terminated, Parser,
+};
+use aoc_common::parse::ParseError;
+use aoc_common::puzzle::Solution;
+use std::ops::Add;
+
+#[derive(Debug, Copy, Clone)]
+struct Vec2 {
+    x: i64,
+    y: i64,
+}
+
+impl Add<i64> for Vec2 {
+    type Output = Self;
+
+    fn add(self, rhs: i64) -> Self::Output {
+        Vec2 {
+            x: self.x + rhs,
+            y: self.y + rhs,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Machine {
+    button_a: Vec2,
+    button_b: Vec2,
+    prize: Vec2,
+}
+
+impl Machine {
+    /// Using solution to the corresponding system of equations
+    fn prize_combination(&self) -> Option<(u64, u64)> {
+        let discriminant = self.button_b.x * self.button_a.y - self.button_a.x * self.button_b.y;
+
+        if discriminant == 0 {
+            // Divide by zero -> no solution
+            return None;
+        }
+
+        let numerator_a = self.button_b.x * self.prize.y - self.button_b.y * self.prize.x;
+        let numerator_b = self.button_a.x * self.prize.y - self.button_a.y * self.prize.x;
+
+        if numerator_a % discriminant > 0 || numerator_b % discriminant != 0 {
+            // Only accept integer solutions
+            return None;
+        }
+
+        Some((
+            (numerator_a / discriminant) as u64,
+            (numerator_b / -discriminant) as u64,
+        ))
+    }
+
+    fn correct_prize(&self) -> Self {
+        Self {
+            button_a: self.button_a,
+            button_b: self.button_b,
+            prize: self.prize + 10_000_000_000_000,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Problem {
+    machines: Vec<Machine>,
+}
+
+impl Problem {
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        // Lines like `Button A: X+94, Y+34`, where the values follow the given prefixes
+        let vector = |label: &'static str, x: &'static str, y: &'static str| {
+            preceded(
+                label,
+                separated_pair(preceded(x, integer()), ", ", preceded(y, integer())),
+            )
+            .map(|(x, y)| Vec2 { x, y })
+        };
+        let machine = pair(
+            terminated(vector("Button A: ", "X+", "Y+"), "\n"),
+            pair(
+                terminated(vector("Button B: ", "X+", "Y+"), "\n"),
+                vector("Prize: ", "X=", "Y="),
+            ),
+        )
+        .map(|(button_a, (button_b, prize))| Machine {
+            button_a,
+            button_b,
+            prize,
+        });
+
+        Ok(Self {
+            machines: sections(machine).parse_all(string.trim())?,
+        })
+    }
+}
+
+impl Solution for Problem {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::from_string(input)
+    }
+
+    fn part_1(&self) -> u64 {
+        self.machines
+            .iter()
+            .filter_map(|machine| machine.prize_combination())
+            .filter(|(times_a, times_b)| *times_a <= 100 && *times_b <= 100)
+            .map(|(times_a, times_b)| times_a * 3 + times_b)
+            .sum()
+    }
+
+    fn part_2(&self) -> u64 {
+        self.machines
+            .iter()
+            .map(|machine| machine.correct_prize())
+            .filter_map(|machine| machine.prize_combination())
+            .map(|(times_a, times_b)| times_a * 3 + times_b)
+            .sum()
+    }
+}
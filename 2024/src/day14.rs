@@ -0,0 +1,273 @@
+use aoc_common::combinator::{integer, preceded, separated_pair, Parser};
+use aoc_common::geometry::Vec2;
+use aoc_common::parse::ParseError;
+use aoc_common::puzzle::{Maybe, Solution};
+use aoc_common::render::{Frame, Rgb};
+
+#[derive(Debug, Copy, Clone)]
+struct Robot {
+    position: Vec2,
+    velocity: Vec2,
+}
+
+impl Robot {
+    /// Parses a line like `p=0,4 v=3,-3`
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        let vector = || separated_pair(integer(), ",", integer()).map(|(x, y)| Vec2::new(x, y));
+
+        separated_pair(preceded("p=", vector()), " ", preceded("v=", vector()))
+            .map(|(position, velocity)| Self { position, velocity })
+            .parse_all(string)
+    }
+
+    fn elapse_time(&self, seconds: u32, area_dims: Vec2) -> Self {
+        // Process boundary wrapping (teleports)
+        let position = (self.position + self.velocity * seconds as i64).rem_euclid(area_dims);
+
+        Self {
+            position,
+            velocity: self.velocity,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Area {
+    dimensions: Vec2,
+    robots: Vec<Robot>,
+}
+
+impl Area {
+    fn from_string(dimensions: Vec2, string: &str) -> Result<Self, ParseError> {
+        let robots = string
+            .lines()
+            .map(Robot::from_string)
+            .collect::<Result<Vec<_>, _>>()?;
+        if robots.is_empty() {
+            return Err(ParseError::after(string, "Expected robots"));
+        }
+
+        Ok(Self { dimensions, robots })
+    }
+
+    fn elapse_time(&self, seconds: u32) -> Self {
+        Self {
+            dimensions: self.dimensions,
+            robots: self
+                .robots
+                .iter()
+                .map(|robot| robot.elapse_time(seconds, self.dimensions))
+                .collect(),
+        }
+    }
+
+    fn quadrants(&self) -> Vec<Self> {
+        let quadrant_dims = self.dimensions / 2;
+
+        (0..2)
+            .flat_map(|i| {
+                (0..2).map(move |j| Self {
+                    dimensions: quadrant_dims,
+                    robots: self
+                        .robots
+                        .iter()
+                        .filter(|robot| {
+                            robot.position.x >= (i * quadrant_dims.x) + i
+                                && robot.position.x < ((i + 1) * quadrant_dims.x) + i
+                                && robot.position.y >= (j * quadrant_dims.y) + j
+                                && robot.position.y < ((j + 1) * quadrant_dims.y) + j
+                        })
+                        .copied()
+                        .collect(),
+                })
+            })
+            .collect()
+    }
+
+    fn robot_count(&self) -> usize {
+        self.robots.len()
+    }
+
+    /// Spread of the robots along one axis, as the variance times the squared number of robots
+    fn spread(&self, coordinate: impl Fn(Vec2) -> i64) -> i64 {
+        let n = self.robots.len() as i64;
+        let (sum, sum_of_squares) = self.robots.iter().fold((0, 0), |(sum, squares), robot| {
+            let value = coordinate(robot.position);
+            (sum + value, squares + value * value)
+        });
+
+        n * sum_of_squares - sum * sum
+    }
+
+    /// Seconds until the robots are bunched up the most, which is when they form the Christmas
+    /// tree. Coordinates along an axis repeat after its dimension, so the seconds with the least
+    /// spread are found per axis and combined with the Chinese remainder theorem.
+    fn most_clustered(&self) -> Option<u32> {
+        let least_spread = |dimension: i64, coordinate: fn(Vec2) -> i64| {
+            (0..dimension)
+                .min_by_key(|&seconds| self.elapse_time(seconds as u32).spread(coordinate))
+                .unwrap()
+        };
+        let (width, height) = (self.dimensions.x, self.dimensions.y);
+        let x = least_spread(width, |position| position.x);
+        let y = least_spread(height, |position| position.y);
+
+        // seconds = x + k * width, where k * width = y - x (mod height)
+        let k = ((y - x) * mod_inverse(width, height)?).rem_euclid(height);
+        Some((x + k * width) as u32)
+    }
+
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::filled(
+            self.dimensions.x as usize,
+            self.dimensions.y as usize,
+            Rgb::BLACK,
+        );
+
+        for position in self
+            .robots
+            .iter()
+            .filter_map(|robot| robot.position.to_position())
+        {
+            if let Some(pixel) = frame.get_mut(position) {
+                *pixel = Rgb::GREEN;
+            }
+        }
+
+        frame
+    }
+}
+
+/// Size of the area of the puzzle input
+const DIMENSIONS: Vec2 = Vec2::new(101, 103);
+
+#[derive(Debug)]
+pub struct Problem {
+    area: Area,
+}
+
+impl Problem {
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            area: Area::from_string(DIMENSIONS, string)?,
+        })
+    }
+}
+
+impl Solution for Problem {
+    type Answer1 = usize;
+    type Answer2 = Maybe<u32>;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::from_string(input)
+    }
+
+    /// Overrides the size of the area of the puzzle input, like for the smaller one of the
+    /// sample
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let value = value
+            .parse()
+            .ok()
+            .filter(|&value| value > 0)
+            .ok_or_else(|| format!("Invalid {}: {}", name, value))?;
+
+        match name {
+            "width" => self.area.dimensions.x = value,
+            "height" => self.area.dimensions.y = value,
+            _ => return Err(format!("Unknown parameter: {}", name)),
+        }
+
+        Ok(())
+    }
+
+    fn part_1(&self) -> usize {
+        self.area
+            .elapse_time(100)
+            .quadrants()
+            .iter()
+            .map(|quadrant| quadrant.robot_count())
+            .reduce(|acc, count| acc * count)
+            .unwrap()
+    }
+
+    /// No answer unless the width and height of the area are coprime
+    fn part_2(&self) -> Maybe<u32> {
+        self.area.most_clustered().into()
+    }
+
+    /// The robots after every second, until they are all back at their starting positions. The
+    /// frame number is the number of seconds, so a range of seconds is rendered with `--from`
+    /// and `--to`.
+    fn render(&self, frame: &mut dyn FnMut(Frame)) {
+        let mut area = self.area.clone();
+
+        for _ in 0..self.area.dimensions.x * self.area.dimensions.y {
+            frame(area.frame());
+            area = area.elapse_time(1);
+        }
+    }
+}
+
+/// Inverse of `a` modulo `m`, found with the extended Euclidean algorithm
+fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    let (mut r, mut next_r) = (a.rem_euclid(m), m);
+    let (mut s, mut next_s) = (1, 0);
+
+    while next_r != 0 {
+        let quotient = r / next_r;
+        (r, next_r) = (next_r, r - quotient * next_r);
+        (s, next_s) = (next_s, s - quotient * next_s);
+    }
+
+    (r == 1).then(|| s.rem_euclid(m))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixture;
+
+    #[test]
+    fn test_dimensions() {
+        let mut problem = Problem::parse(&fixture!("day14").input).unwrap();
+        assert_eq!(DIMENSIONS, problem.area.dimensions);
+
+        problem.set_param("width", "11").unwrap();
+        problem.set_param("height", "7").unwrap();
+        assert_eq!(Vec2::new(11, 7), problem.area.dimensions);
+
+        assert!(problem.set_param("depth", "3").is_err());
+        assert!(problem.set_param("width", "0").is_err());
+        assert!(problem.set_param("height", "-7").is_err());
+        assert!(Problem::parse("").is_err());
+    }
+
+    #[test]
+    fn test_most_clustered() {
+        // Robots that all end up in the middle after 500 seconds, among scattered ones
+        let dimensions = Vec2::new(31, 37);
+        let robots = (0..60)
+            .map(|i| {
+                let velocity = Vec2::new(i * 7 % 13 - 6, i * 5 % 11 - 5);
+                let position = match i % 3 {
+                    0 => Vec2::new(i * 17, i * 23),
+                    _ => Vec2::new(15 + i % 4, 18 + i % 5) - velocity * 500,
+                };
+
+                Robot {
+                    position: position.rem_euclid(dimensions),
+                    velocity,
+                }
+            })
+            .collect();
+
+        assert_eq!(Some(500), Area { dimensions, robots }.most_clustered());
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(Some(51), mod_inverse(101, 103));
+        assert_eq!(Some(4), mod_inverse(-1, 5));
+        assert_eq!(None, mod_inverse(4, 6));
+    }
+}
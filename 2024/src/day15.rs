@@ -0,0 +1,245 @@
+use aoc_common::geometry::{Direction, Vec2};
+use aoc_common::grid::Grid;
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
+use aoc_common::render::{Frame, Rgb};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone)]
+struct Map {
+    dimensions: Vec2,
+    /// Leftmost cells of the boxes, which are `box_width` cells wide
+    boxes: HashSet<Vec2>,
+    box_width: i64,
+    walls: HashSet<Vec2>,
+    robot_pos: Vec2,
+    directions: Vec<Direction>,
+}
+
+impl Map {
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        let (top, bottom) = string
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::after(string, "Missing blank line before the moves"))?;
+
+        let grid = Grid::parse_with(top, |char| match char {
+            '#' | '.' | 'O' | '@' => Ok(char),
+            _ => Err("Unknown tile"),
+        })?;
+        let objects = |object: char| {
+            grid.iter()
+                .filter(|&(_, &char)| char == object)
+                .map(|(pos, _)| Vec2::from(pos))
+                .collect::<HashSet<_>>()
+        };
+
+        let mut directions = vec![];
+        for line in bottom.lines() {
+            for (i, char) in line.char_indices() {
+                directions.push(
+                    Direction::try_from(char).map_err(|_| {
+                        ParseError::at(parse::char_at(line, i), "Unknown direction")
+                    })?,
+                );
+            }
+        }
+
+        Ok(Self {
+            dimensions: Vec2::new(grid.width() as i64, grid.height() as i64),
+            boxes: objects('O'),
+            box_width: 1,
+            walls: objects('#'),
+            robot_pos: objects('@')
+                .into_iter()
+                .next()
+                .ok_or_else(|| ParseError::at(top, "No robot found"))?,
+            directions,
+        })
+    }
+
+    /// The scaled-up warehouse, in which everything except the robot is twice as wide
+    fn widen(&self) -> Self {
+        let widen = |pos: &Vec2| Vec2::new(pos.x * 2, pos.y);
+
+        Self {
+            dimensions: widen(&self.dimensions),
+            boxes: self.boxes.iter().map(widen).collect(),
+            box_width: self.box_width * 2,
+            walls: self
+                .walls
+                .iter()
+                .flat_map(|wall| [widen(wall), widen(wall) + Vec2::new(1, 0)])
+                .collect(),
+            robot_pos: widen(&self.robot_pos),
+            directions: self.directions.clone(),
+        }
+    }
+
+    /// Leftmost cell of the box covering the given position, if any
+    fn box_at(&self, pos: Vec2) -> Option<Vec2> {
+        (0..self.box_width)
+            .map(|offset| pos - Vec2::new(offset, 0))
+            .find(|left| self.boxes.contains(left))
+    }
+
+    /// Performs all moves, passing the map after every move to the given function
+    fn move_robot(&self, mut on_move: impl FnMut(&Self)) -> Self {
+        let mut map = self.clone();
+
+        for &direction in self.directions.iter() {
+            map.step(direction);
+            on_move(&map);
+        }
+
+        map
+    }
+
+    /// Moves the robot, pushing all boxes in its way. A box pushes every box in front of any of
+    /// its cells, so pushing wide boxes up or down can move a whole tree of them. Nothing moves
+    /// when any of them is blocked by a wall.
+    fn step(&mut self, direction: Direction) {
+        let mut boxes_to_move = vec![];
+        let mut pushed = vec![self.robot_pos + direction.vec()];
+
+        while let Some(position) = pushed.pop() {
+            if self.walls.contains(&position) {
+                return;
+            }
+
+            if let Some(left) = self.box_at(position) {
+                if boxes_to_move.contains(&left) {
+                    continue;
+                }
+                boxes_to_move.push(left);
+
+                // Cells in front of the box, except those of the box itself
+                pushed.extend(
+                    (0..self.box_width)
+                        .map(|offset| left + Vec2::new(offset, 0) + direction.vec())
+                        .filter(|&next| self.box_at(next) != Some(left)),
+                );
+            }
+        }
+
+        // Perform movements
+        for box_pos in boxes_to_move.iter() {
+            self.boxes.remove(box_pos);
+        }
+        for &box_pos in boxes_to_move.iter() {
+            self.boxes.insert(box_pos + direction.vec());
+        }
+
+        // Once the boxes are moved, we can move the robot
+        self.robot_pos += direction.vec();
+    }
+
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::filled(
+            self.dimensions.x as usize,
+            self.dimensions.y as usize,
+            Rgb::BLACK,
+        );
+
+        for (x, y) in frame.positions() {
+            let position = Vec2::from((x, y));
+
+            frame[(x, y)] = match position {
+                _ if position == self.robot_pos => Rgb::YELLOW,
+                _ if self.box_at(position).is_some() => Rgb::BROWN,
+                _ if self.walls.contains(&position) => Rgb::GREY,
+                _ => Rgb::BLACK,
+            };
+        }
+
+        frame
+    }
+
+    fn box_gps_sum(&self) -> u32 {
+        self.boxes
+            .iter()
+            .map(|box_pos| (box_pos.x + box_pos.y * 100) as u32)
+            .sum()
+    }
+}
+
+#[derive(Debug)]
+pub struct Problem {
+    map: Map,
+}
+
+impl Problem {
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            map: Map::from_string(string)?,
+        })
+    }
+}
+
+impl Solution for Problem {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::from_string(input)
+    }
+
+    fn part_1(&self) -> u32 {
+        self.map.move_robot(|_| ()).box_gps_sum()
+    }
+
+    fn part_2(&self) -> u32 {
+        self.map.widen().move_robot(|_| ()).box_gps_sum()
+    }
+
+    /// The robot pushing the boxes around the warehouse, a frame per move
+    fn render(&self, frame: &mut dyn FnMut(Frame)) {
+        frame(self.map.frame());
+        self.map.move_robot(|map| frame(map.frame()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wide_boxes() {
+        let map = Map::from_string(
+            r#"#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^"#,
+        )
+        .unwrap()
+        .widen();
+
+        // The first push to the left moves both boxes on the robot's row
+        let mut moves = vec![];
+        let moved = map.move_robot(|map| moves.push(map.clone()));
+        assert_eq!(
+            HashSet::from([(5, 3), (7, 3), (6, 4)].map(Vec2::from)),
+            moves[0].boxes
+        );
+
+        // Pushing up moves the tree of three boxes, until the wall stops them
+        assert_eq!(Vec2::new(7, 4), moves[5].robot_pos);
+        assert_eq!(
+            HashSet::from([(5, 2), (7, 2), (6, 3)].map(Vec2::from)),
+            moves[5].boxes
+        );
+        assert_eq!(moves[5].boxes, moves[6].boxes);
+        assert_eq!(moves[5].robot_pos, moves[6].robot_pos);
+
+        assert_eq!(Vec2::new(5, 2), moved.robot_pos);
+        assert_eq!(
+            HashSet::from([(5, 1), (7, 2), (6, 3)].map(Vec2::from)),
+            moved.boxes
+        );
+        assert_eq!(618, moved.box_gps_sum());
+    }
+}
@@ -0,0 +1,161 @@
+use aoc_common::geometry::{Direction, Vec2};
+use aoc_common::grid::Grid;
+use aoc_common::parse::ParseError;
+use aoc_common::puzzle::{Maybe, Solution};
+use aoc_common::render::{Frame, Rgb};
+use aoc_common::shortest_path::{self, ShortestPaths};
+use std::collections::HashSet;
+
+#[derive(Debug)]
+struct Map {
+    width: usize,
+    height: usize,
+    walls: HashSet<Vec2>,
+    start: Vec2,
+    finish: Vec2,
+}
+
+impl Map {
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse_with(string, |char| match char {
+            '#' | '.' | 'S' | 'E' => Ok(char),
+            _ => Err("Unknown tile"),
+        })?;
+        let find = |tile: char, name: &str| {
+            grid.find(|&char| char == tile)
+                .map(Vec2::from)
+                .ok_or_else(|| ParseError::after(string.trim_end(), format!("No {} found", name)))
+        };
+
+        Ok(Self {
+            width: grid.width(),
+            height: grid.height(),
+            walls: grid
+                .iter()
+                .filter(|(_, &char)| char == '#')
+                .map(|(pos, _)| pos.into())
+                .collect(),
+            start: find('S', "start")?,
+            finish: find('E', "end")?,
+        })
+    }
+
+    /// Reindeer facing the given direction, and the states it can move to with their points:
+    /// a step forward for 1 point or a quarter turn for 1000 points. Steps never leave the map,
+    /// even when its border has gaps.
+    fn moves(&self, (position, direction): (Vec2, Direction)) -> Vec<((Vec2, Direction), u32)> {
+        let mut moves = vec![
+            ((position, direction.turn_left()), 1000),
+            ((position, direction.turn_right()), 1000),
+        ];
+        let next = position + direction.vec();
+        let on_map = next
+            .to_position()
+            .is_some_and(|(x, y)| x < self.width && y < self.height);
+        if on_map && !self.walls.contains(&next) {
+            moves.push(((next, direction), 1));
+        }
+
+        moves
+    }
+
+    /// Search from the start, facing east, to the finish in any direction. Besides the lowest
+    /// score it keeps the predecessors of each state at that score, so every route with the
+    /// lowest score can be traced back from the finish.
+    ///
+    /// Dijkstra with priority queue. Never used this combination before so learned from this
+    /// example: https://doc.rust-lang.org/nightly/std/collections/binary_heap/index.html#examples
+    fn best_paths(&self) -> ShortestPaths<(Vec2, Direction), u32> {
+        shortest_path::dijkstra(
+            (self.start, Direction::Right),
+            |&state| self.moves(state),
+            |&(position, _)| position == self.finish,
+        )
+    }
+
+    fn points_from_to(&self) -> Option<u32> {
+        self.best_paths().cost()
+    }
+
+    /// Tiles on any of the routes with the lowest score, if the finish can be reached
+    fn best_path_tiles(&self) -> Option<HashSet<Vec2>> {
+        let paths = self.best_paths();
+        paths.cost()?;
+
+        Some(
+            paths
+                .on_optimal_paths()
+                .into_iter()
+                .map(|(position, _)| position)
+                .collect(),
+        )
+    }
+
+    fn frame(&self, path: &HashSet<Vec2>) -> Frame {
+        let mut frame = Frame::filled(self.width, self.height, Rgb::BLACK);
+        let mut paint = |tile: Vec2, colour| {
+            if let Some(pixel) = tile.to_position().and_then(|pos| frame.get_mut(pos)) {
+                *pixel = colour;
+            }
+        };
+
+        self.walls.iter().for_each(|&wall| paint(wall, Rgb::GREY));
+        path.iter().for_each(|&tile| paint(tile, Rgb::BLUE));
+        paint(self.start, Rgb::GREEN);
+        paint(self.finish, Rgb::RED);
+
+        frame
+    }
+}
+
+#[derive(Debug)]
+pub struct Problem {
+    map: Map,
+}
+
+impl Problem {
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            map: Map::from_string(string)?,
+        })
+    }
+}
+
+impl Solution for Problem {
+    type Answer1 = Maybe<u32>;
+    type Answer2 = Maybe<usize>;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::from_string(input)
+    }
+
+    fn part_1(&self) -> Maybe<u32> {
+        self.map.points_from_to().into()
+    }
+
+    fn part_2(&self) -> Maybe<usize> {
+        self.map.best_path_tiles().map(|tiles| tiles.len()).into()
+    }
+
+    /// The maze with the tiles of all best paths
+    fn render(&self, frame: &mut dyn FnMut(Frame)) {
+        frame(
+            self.map
+                .frame(&self.map.best_path_tiles().unwrap_or_default()),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open_maze() {
+        // The bottom row of walls is missing, and the finish is walled off
+        let map = Map::from_string("#####\n#S#E#\n#.###\n#...#").unwrap();
+
+        assert_eq!(None, map.points_from_to());
+        assert_eq!(None, map.best_path_tiles());
+    }
+}
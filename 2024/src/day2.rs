@@ -1,4 +1,5 @@
-use std::fs;
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
 
 #[derive(Debug, Eq, PartialEq)]
 struct Report {
@@ -27,13 +28,14 @@ impl Report {
         // Return index of left operand if the absolute difference with the right operand exceeds
         // range 1-3 or when the sign of the difference does not match the sign of the average
         // gradient of the report.
-        match self.levels.windows(2).enumerate().find(|(_, window)| {
-            (window[1] - window[0]).signum() != gradient_sign
-                || !(1..=3).contains(&window[1].abs_diff(window[0]))
-        }) {
-            None => None,
-            Some((index, _)) => Some(index),
-        }
+        self.levels
+            .windows(2)
+            .enumerate()
+            .find(|(_, window)| {
+                (window[1] - window[0]).signum() != gradient_sign
+                    || !(1..=3).contains(&window[1].abs_diff(window[0]))
+            })
+            .map(|(index, _)| index)
     }
 
     fn is_safe(&self) -> bool {
@@ -59,24 +61,27 @@ impl Report {
     }
 }
 
-struct Problem {
+pub struct Problem {
     reports: Vec<Report>,
 }
 
 impl Problem {
-    fn from_string(string: &str) -> Self {
-        Self {
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        Ok(Self {
             reports: string
                 .lines()
-                .map(|line| {
-                    Report::new(
-                        line.split_whitespace()
-                            .map(|value| value.parse::<i32>().unwrap())
-                            .collect(),
-                    )
-                })
-                .collect(),
-        }
+                .map(|line| Ok(Report::new(parse::numbers(line)?)))
+                .collect::<Result<_, ParseError>>()?,
+        })
+    }
+}
+
+impl Solution for Problem {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::from_string(input)
     }
 
     fn part_1(&self) -> usize {
@@ -94,38 +99,10 @@ impl Problem {
     }
 }
 
-fn main() {
-    let problem = Problem::from_string(
-        fs::read_to_string("input/day2.txt")
-            .expect("Failed to read input")
-            .as_str(),
-    );
-
-    println!("Part 1: {}", problem.part_1()); // Attempts: 524
-    println!("Part 2: {}", problem.part_2()); // Attempts: 549 (too low), 554 (too low), 568 (too low), 569
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const SAMPLE: &str = "7 6 4 2 1
-                          1 2 7 8 9
-                          9 7 6 2 1
-                          1 3 2 4 5
-                          8 6 4 4 1
-                          1 3 6 7 9";
-
-    #[test]
-    fn test_sample_part_1() {
-        assert_eq!(2, Problem::from_string(SAMPLE).part_1());
-    }
-
-    #[test]
-    fn test_sample_part_2() {
-        assert_eq!(4, Problem::from_string(SAMPLE).part_2());
-    }
-
     #[test]
     fn test_unsafe_level_index() {
         // Sample reports
@@ -163,32 +140,29 @@ mod tests {
     #[test]
     fn test_is_safe_tolerated() {
         // Sample reports
-        assert_eq!(true, Report::new(vec![7, 6, 4, 2, 1]).is_safe_tolerated());
-        assert_eq!(false, Report::new(vec![1, 2, 7, 8, 9]).is_safe_tolerated());
-        assert_eq!(false, Report::new(vec![9, 7, 6, 2, 1]).is_safe_tolerated());
-        assert_eq!(true, Report::new(vec![1, 3, 2, 4, 5]).is_safe_tolerated());
-        assert_eq!(true, Report::new(vec![8, 6, 4, 4, 1]).is_safe_tolerated());
-        assert_eq!(true, Report::new(vec![1, 3, 6, 7, 9]).is_safe_tolerated());
+        assert!(Report::new(vec![7, 6, 4, 2, 1]).is_safe_tolerated());
+        assert!(!Report::new(vec![1, 2, 7, 8, 9]).is_safe_tolerated());
+        assert!(!Report::new(vec![9, 7, 6, 2, 1]).is_safe_tolerated());
+        assert!(Report::new(vec![1, 3, 2, 4, 5]).is_safe_tolerated());
+        assert!(Report::new(vec![8, 6, 4, 4, 1]).is_safe_tolerated());
+        assert!(Report::new(vec![1, 3, 6, 7, 9]).is_safe_tolerated());
 
         // Other
-        assert_eq!(true, Report::new(vec![1, 1, 2, 3, 4]).is_safe_tolerated());
-        assert_eq!(false, Report::new(vec![1, 1, 1, 3, 4]).is_safe_tolerated());
-        assert_eq!(true, Report::new(vec![4, 4, 3, 2, 1]).is_safe_tolerated());
-        assert_eq!(false, Report::new(vec![4, 4, 4, 2, 1]).is_safe_tolerated());
-        assert_eq!(true, Report::new(vec![2, 3, 3, 6, 7]).is_safe_tolerated());
-        assert_eq!(false, Report::new(vec![3, 3, 3, 6, 7]).is_safe_tolerated());
+        assert!(Report::new(vec![1, 1, 2, 3, 4]).is_safe_tolerated());
+        assert!(!Report::new(vec![1, 1, 1, 3, 4]).is_safe_tolerated());
+        assert!(Report::new(vec![4, 4, 3, 2, 1]).is_safe_tolerated());
+        assert!(!Report::new(vec![4, 4, 4, 2, 1]).is_safe_tolerated());
+        assert!(Report::new(vec![2, 3, 3, 6, 7]).is_safe_tolerated());
+        assert!(!Report::new(vec![3, 3, 3, 6, 7]).is_safe_tolerated());
 
         // This case was causing the faulty first attempts (gradient was based on first two levels)
-        assert_eq!(true, Report::new(vec![3, 1, 2, 3]).is_safe_tolerated());
+        assert!(Report::new(vec![3, 1, 2, 3]).is_safe_tolerated());
 
         // Case where comparing first with last to determine gradient would fail
-        assert_eq!(true, Report::new(vec![5, 2, 3, 4]).is_safe_tolerated());
+        assert!(Report::new(vec![5, 2, 3, 4]).is_safe_tolerated());
 
         // From actual input
-        assert_eq!(
-            true,
-            Report::new(vec![47, 49, 50, 52, 53, 54, 57, 59]).is_safe_tolerated()
-        );
+        assert!(Report::new(vec![47, 49, 50, 52, 53, 54, 57, 59]).is_safe_tolerated());
     }
 
     #[test]
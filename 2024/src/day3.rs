@@ -1,6 +1,7 @@
-use std::fs;
+use aoc_common::parse::ParseError;
+use aoc_common::puzzle::Solution;
 
-struct Problem {
+pub struct Problem {
     instructions: String,
 }
 
@@ -11,22 +12,32 @@ impl Problem {
         }
     }
 
-    fn process(&self, instructions: &String) -> usize {
+    fn process(&self, instructions: &str) -> usize {
         instructions
             .match_indices("mul(")
             .filter_map(|(start, _)| {
                 let offset = start + 4; // length of "mul("
                 instructions[offset..(offset + instructions[offset..].find(")")?)].split_once(',')
             })
+            // Corrupted instructions are skipped, so the operands must be plain numbers
             .filter(|(left_operand, right_operand)| {
                 left_operand.chars().all(|char| char.is_ascii_digit())
                     && right_operand.chars().all(|char| char.is_ascii_digit())
             })
-            .map(|(left_operand, right_operand)| {
-                left_operand.parse::<usize>().unwrap() * right_operand.parse::<usize>().unwrap()
+            .filter_map(|(left_operand, right_operand)| {
+                Some(left_operand.parse::<usize>().ok()? * right_operand.parse::<usize>().ok()?)
             })
             .sum()
     }
+}
+
+impl Solution for Problem {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self::from_string(input))
+    }
 
     fn part_1(&self) -> usize {
         self.process(&self.instructions)
@@ -50,41 +61,3 @@ impl Problem {
         )
     }
 }
-
-fn main() {
-    let problem = Problem::from_string(
-        fs::read_to_string("input/day3.txt")
-            .expect("Failed to read input")
-            .as_str(),
-    );
-
-    println!("Part 1: {}", problem.part_1()); // Attempts: 185797128
-    println!("Part 2: {}", problem.part_2()); // Attempts: 144809740 (too high), 89798695
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_sample_part_1() {
-        assert_eq!(
-            161,
-            Problem::from_string(
-                "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))"
-            )
-            .part_1()
-        );
-    }
-
-    #[test]
-    fn test_sample_part_2() {
-        assert_eq!(
-            48,
-            Problem::from_string(
-                "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"
-            )
-            .part_2()
-        );
-    }
-}
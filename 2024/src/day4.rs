@@ -1,27 +1,23 @@
-use std::fs;
+use aoc_common::grid::Grid;
+use aoc_common::parse::ParseError;
+use aoc_common::puzzle::Solution;
 
-struct Problem {
-    width: usize,
-    chars: Vec<char>,
+pub struct Problem {
+    grid: Grid<char>,
 }
 
 impl Problem {
-    fn from_string(string: &str) -> Self {
-        Self {
-            width: string.find("\n").unwrap(),
-            chars: string.lines().flat_map(|line| line.chars()).collect(),
-        }
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            grid: string.parse()?,
+        })
     }
 
     fn char_at(&self, x: usize, y: usize) -> Option<&char> {
-        if x >= self.width {
-            return None;
-        }
-
-        self.chars.get(x + y * self.width)
+        self.grid.get((x, y))
     }
 
-    fn directions(&self) -> [(i32, i32); 8] {
+    fn directions(&self) -> [(i64, i64); 8] {
         [
             (-1, -1),
             (0, -1),
@@ -35,28 +31,20 @@ impl Problem {
     }
 
     fn word_count(&self, word: String) -> usize {
-        self.chars
+        self.grid
             .iter()
-            .enumerate()
             // Minimal optimization: only search for chars matching first letter of the word
             .filter(|(_, &char)| char == word.chars().nth(0).unwrap())
-            .map(|(index, _)| {
-                let x = index % self.width;
-                let y = index / self.width;
-
+            .map(|((x, y), _)| {
                 self.directions()
                     .iter()
                     .filter(|(dx, dy)| {
                         (0..word.len())
                             .filter_map(|i| {
-                                let x2 = x as i32 + (i as i32 * dx);
-                                let y2 = y as i32 + (i as i32 * dy);
-
-                                if x2 < 0 || y2 < 0 {
-                                    return None;
-                                }
-
-                                self.char_at(x2 as usize, y2 as usize)
+                                self.grid.get_signed(
+                                    x as i64 + (i as i64 * dx),
+                                    y as i64 + (i as i64 * dy),
+                                )
                             })
                             .collect::<String>()
                             == word
@@ -65,6 +53,15 @@ impl Problem {
             })
             .sum()
     }
+}
+
+impl Solution for Problem {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::from_string(input)
+    }
 
     fn part_1(&self) -> usize {
         self.word_count("XMAS".to_string())
@@ -72,14 +69,13 @@ impl Problem {
 
     /// Lazy implementation for part 2
     fn part_2(&self) -> usize {
-        self.chars
+        self.grid
             .iter()
-            .enumerate()
             .filter(|(_, &char)| char == 'A') // only look at tiles containing an A
-            .map(|(index, _)| (index % self.width, index / self.width)) // index to coordinates
+            .map(|(pos, _)| pos)
             .filter(|(x, y)| *x > 0 && *y > 0) // ignore first row and column (prevents overflow)
             .filter(|(x, y)| {
-                let seq = vec![
+                let seq = [
                     self.char_at(x - 1, y - 1),
                     self.char_at(x + 1, y - 1),
                     self.char_at(x - 1, y + 1),
@@ -95,49 +91,18 @@ impl Problem {
     }
 }
 
-fn main() {
-    let problem = Problem::from_string(
-        fs::read_to_string("input/day4.txt")
-            .expect("Failed to read input")
-            .as_str(),
-    );
-
-    println!("Part 1: {}", problem.part_1()); // Attempts: 2545
-    println!("Part 2: {}", problem.part_2()); // Attempts: 1886
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const SAMPLE: &str = "MMMSXXMASM\n\
-                          MSAMXMSMSA\n\
-                          AMXSXMAAMM\n\
-                          MSAMASMSMX\n\
-                          XMASAMXAMM\n\
-                          XXAMMXXAMA\n\
-                          SMSMSASXSS\n\
-                          SAXAMASAAA\n\
-                          MAMMMXMMMM\n\
-                          MXMXAXMASX";
-
-    #[test]
-    fn test_sample_part_1() {
-        assert_eq!(18, Problem::from_string(SAMPLE).part_1());
-    }
-
-    #[test]
-    fn test_sample_part_2() {
-        assert_eq!(9, Problem::from_string(SAMPLE).part_2());
-    }
-
     #[test]
     fn test_char_at() {
         let problem = Problem::from_string(
             "123\n\
             456\n\
             789",
-        );
+        )
+        .unwrap();
 
         assert_eq!(Some(&'1'), problem.char_at(0, 0));
         assert_eq!(Some(&'2'), problem.char_at(1, 0));
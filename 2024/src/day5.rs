@@ -1,34 +1,40 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::fs;
 
 #[derive(Debug)]
-struct Problem {
+pub struct Problem {
     rules: HashMap<u32, Vec<u32>>,
     updates: Vec<Vec<u32>>,
 }
 
 impl Problem {
-    fn from_string(string: &str) -> Self {
-        let (top, bottom) = string.split_once("\n\n").expect("Invalid input");
-
-        Self {
-            rules: top
-                .lines()
-                .map(|line| line.split_once("|").expect("Invalid rule line"))
-                .fold(HashMap::new(), |mut rules, (page_left, page_right)| {
-                    rules
-                        .entry(page_left.parse().unwrap())
-                        .or_insert(vec![])
-                        .push(page_right.parse().unwrap());
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        let (top, bottom) = string
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::after(string, "Missing blank line before the updates"))?;
+
+        let mut rules = HashMap::new();
+        for line in top.lines() {
+            let (page_left, page_right) = parse::split_once(line, "|")?;
+            rules
+                .entry(parse::token(page_left, "page")?)
+                .or_insert(vec![])
+                .push(parse::token(page_right, "page")?);
+        }
 
-                    rules
-                }),
+        Ok(Self {
+            rules,
             updates: bottom
                 .lines()
-                .map(|line| line.split(",").map(|page| page.parse().unwrap()).collect())
-                .collect(),
-        }
+                .map(|line| {
+                    line.split(",")
+                        .map(|page| parse::token(page, "page"))
+                        .collect()
+                })
+                .collect::<Result<_, _>>()?,
+        })
     }
 
     fn valid_updates(&self) -> Vec<&Vec<u32>> {
@@ -67,6 +73,15 @@ impl Problem {
             })
             .collect()
     }
+}
+
+impl Solution for Problem {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::from_string(input)
+    }
 
     fn part_1(&self) -> u32 {
         self.valid_updates()
@@ -95,58 +110,3 @@ impl Problem {
             .sum()
     }
 }
-
-fn main() {
-    let problem = Problem::from_string(
-        fs::read_to_string("input/day5.txt")
-            .expect("Failed to read input")
-            .as_str(),
-    );
-
-    println!("Part 1: {}", problem.part_1()); // Attempts: 5948
-    println!("Part 2: {}", problem.part_2()); // Attempts: 3062
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE: &str = "47|53\n\
-                          97|13\n\
-                          97|61\n\
-                          97|47\n\
-                          75|29\n\
-                          61|13\n\
-                          75|53\n\
-                          29|13\n\
-                          97|29\n\
-                          53|29\n\
-                          61|53\n\
-                          97|53\n\
-                          61|29\n\
-                          47|13\n\
-                          75|47\n\
-                          97|75\n\
-                          47|61\n\
-                          75|61\n\
-                          47|29\n\
-                          75|13\n\
-                          53|13\n\
-                          \n\
-                          75,47,61,53,29\n\
-                          97,61,53,29,13\n\
-                          75,29,13\n\
-                          75,97,47,61,53\n\
-                          61,13,29\n\
-                          97,13,75,29,47";
-
-    #[test]
-    fn test_sample_part_1() {
-        assert_eq!(143, Problem::from_string(SAMPLE).part_1());
-    }
-
-    #[test]
-    fn test_sample_part_2() {
-        assert_eq!(123, Problem::from_string(SAMPLE).part_2());
-    }
-}
@@ -0,0 +1,185 @@
+use aoc_common::geometry::{Direction, Vec2};
+use aoc_common::grid::Grid;
+use aoc_common::parse::ParseError;
+use aoc_common::puzzle::Solution;
+use aoc_common::render::{Frame, Rgb};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct Problem {
+    bounds: (Vec2, Vec2),
+    position: Vec2,
+    direction: Direction,
+    obstructions: HashMap<Vec2, bool>,
+}
+
+impl Problem {
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        let grid = string.parse::<Grid<char>>()?;
+        let position = grid
+            .find(|&char| char == '^')
+            .ok_or_else(|| ParseError::after(string.trim_end(), "No starting position found"))?;
+
+        Ok(Self {
+            bounds: (
+                Vec2::ZERO,
+                Vec2::new(grid.width() as i64 - 1, grid.height() as i64 - 1),
+            ),
+            position: position.into(),
+            direction: Direction::Up,
+            obstructions: grid
+                .iter()
+                .filter(|(_, char)| **char == '#')
+                .map(|(position, _)| (position.into(), true))
+                .collect(),
+        })
+    }
+
+    fn traverse(
+        &self,
+        obstructions: &HashMap<Vec2, bool>,
+        mut move_callback: impl FnMut(Vec2),
+        mut turn_callback: impl FnMut(Vec2, Direction) -> bool,
+    ) -> bool {
+        let mut direction = self.direction;
+        let mut position = self.position;
+
+        loop {
+            // Determine the next position, but do not move there yet
+            let new_position = position + direction.vec();
+
+            // Check whether the new position lies on the map, if not stop
+            if new_position.x < self.bounds.0.x
+                || new_position.x > self.bounds.1.x
+                || new_position.y < self.bounds.0.y
+                || new_position.y > self.bounds.1.y
+            {
+                // Return true to indicate the traversal stopped naturally (reaching map boundary)
+                return true;
+            }
+
+            // Obstruction at new position, so rotate direction 90 degrees clockwise
+            if obstructions.contains_key(&new_position) {
+                direction = direction.turn_right();
+
+                // Turn callback can also function as circuit breaker
+                if turn_callback(position, direction) {
+                    // Return false to indicate the traversal was stopped prematurely
+                    return false;
+                }
+
+                continue;
+            }
+
+            // Move to the new position
+            position = new_position;
+
+            move_callback(position);
+        }
+    }
+
+    fn traversal_path(&self) -> Vec<Vec2> {
+        let mut visited: Vec<Vec2> = vec![self.position];
+
+        self.traverse(
+            &self.obstructions,
+            |position| {
+                if !visited.contains(&position) {
+                    visited.push(position);
+                }
+            },
+            |_, _| false,
+        );
+
+        visited
+    }
+}
+
+impl Solution for Problem {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::from_string(input)
+    }
+
+    fn part_1(&self) -> usize {
+        self.traversal_path().len()
+    }
+
+    fn part_2(&self) -> usize {
+        self.traversal_path()
+            .iter()
+            .filter(move |&&position| {
+                // Insert an obstruction at each unique position sequentially, then check for loops
+                let mut new_obstructions = self.obstructions.clone();
+                new_obstructions.insert(position, true);
+
+                let mut visited: HashMap<(Vec2, Direction), bool> = HashMap::new();
+                visited.insert((self.position, self.direction), true);
+
+                // Only include obstruction variations whose traversal was not completed due to loop detection
+                !self.traverse(
+                    &new_obstructions,
+                    |_| (),
+                    |position, direction| {
+                        // Detect loops by checking whether a position was already visited with the same direction
+                        if visited.contains_key(&(position, direction)) {
+                            return true;
+                        }
+
+                        visited.insert((position, direction), true);
+
+                        false
+                    },
+                )
+            })
+            .count()
+    }
+
+    /// The guard walking the lab until leaving it, marking the path behind it
+    fn render(&self, frame: &mut dyn FnMut(Frame)) {
+        let mut lab = Frame::filled(
+            self.bounds.1.x as usize + 1,
+            self.bounds.1.y as usize + 1,
+            Rgb::BLACK,
+        );
+        for position in self.obstructions.keys().filter_map(|pos| pos.to_position()) {
+            lab[position] = Rgb::GREY;
+        }
+
+        let mut guard = self.position.to_position();
+        if let Some(position) = guard {
+            lab[position] = Rgb::YELLOW;
+        }
+        frame(lab.clone());
+
+        self.traverse(
+            &self.obstructions,
+            |position| {
+                if let Some(previous) = guard {
+                    lab[previous] = Rgb::BLUE;
+                }
+                guard = position.to_position();
+                if let Some(position) = guard {
+                    lab[position] = Rgb::YELLOW;
+                }
+                frame(lab.clone());
+            },
+            |_, _| false,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixture;
+
+    #[test]
+    fn test_start_position() {
+        let problem = Problem::from_string(&fixture!("day6").input).unwrap();
+
+        assert_eq!(Vec2::new(4, 6), problem.position);
+    }
+}
@@ -1,4 +1,5 @@
-use std::fs;
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
 
 #[derive(Debug)]
 enum Operator {
@@ -14,16 +15,18 @@ struct Equation {
 }
 
 impl Equation {
-    fn from_string(string: &str) -> Self {
-        let (left, right) = string.split_once(": ").unwrap();
-
-        Self {
-            outcome: left.parse().unwrap(),
-            operands: right
-                .split_whitespace()
-                .map(|operand| operand.parse().unwrap())
-                .collect(),
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        let (left, right) = parse::split_once(string, ": ")?;
+        let operands = parse::numbers(right)?;
+
+        if operands.len() < 2 {
+            return Err(ParseError::at(right, "Expected at least two operands"));
         }
+
+        Ok(Self {
+            outcome: parse::number(left)?,
+            operands,
+        })
     }
 
     fn is_solvable(&self, operators: &[Operator]) -> bool {
@@ -55,18 +58,27 @@ impl Equation {
 }
 
 #[derive(Debug)]
-struct Problem {
+pub struct Problem {
     equations: Vec<Equation>,
 }
 
 impl Problem {
-    fn from_string(string: &str) -> Self {
-        Self {
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        Ok(Self {
             equations: string
                 .lines()
-                .map(|line| Equation::from_string(line))
-                .collect(),
-        }
+                .map(Equation::from_string)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl Solution for Problem {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::from_string(input)
     }
 
     fn part_1(&self) -> usize {
@@ -86,59 +98,45 @@ impl Problem {
     }
 }
 
-fn main() {
-    let problem = Problem::from_string(
-        fs::read_to_string("input/day7.txt")
-            .expect("Failed to read input")
-            .as_str(),
-    );
-
-    println!("Part 1: {}", problem.part_1()); // Attempts: 538191549061
-    println!("Part 2: {}", problem.part_2()); // Attempts: 34612812972206
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const SAMPLE: &str = r#"190: 10 19
-3267: 81 40 27
-83: 17 5
-156: 15 6
-7290: 6 8 6 15
-161011: 16 10 13
-192: 17 8 14
-21037: 9 7 18 13
-292: 11 6 16 20"#;
-
     #[test]
-    fn test_sample_part_1() {
-        let problem = Problem::from_string(SAMPLE);
-
-        assert_eq!(3749, problem.part_1());
+    fn test_equation_is_solvable() {
+        let operators = [Operator::Add, Operator::Mul];
+        assert!(Equation::from_string("190: 10 19")
+            .unwrap()
+            .is_solvable(&operators));
+        assert!(Equation::from_string("3267: 81 40 27")
+            .unwrap()
+            .is_solvable(&operators));
+        assert!(!Equation::from_string("21037: 9 7 18 13")
+            .unwrap()
+            .is_solvable(&operators));
     }
 
     #[test]
-    fn test_sample_part_2() {
-        let problem = Problem::from_string(SAMPLE);
+    fn test_parse_error() {
+        let input = "190: 10 19\n3267: 81 4O 27\n";
 
-        assert_eq!(11387, problem.part_2());
-    }
-
-    #[test]
-    fn test_equation_is_solvable() {
-        let operators = [Operator::Add, Operator::Mul];
         assert_eq!(
-            true,
-            Equation::from_string("190: 10 19").is_solvable(&operators)
-        );
-        assert_eq!(
-            true,
-            Equation::from_string("3267: 81 40 27").is_solvable(&operators)
+            "line 2, column 10: Invalid number: \"4O\"",
+            Problem::parse(input)
+                .err()
+                .unwrap()
+                .locate(input)
+                .to_string()
         );
+
+        let input = "190: 10";
         assert_eq!(
-            false,
-            Equation::from_string("21037: 9 7 18 13").is_solvable(&operators)
+            "line 1, column 6: Expected at least two operands: \"10\"",
+            Problem::parse(input)
+                .err()
+                .unwrap()
+                .locate(input)
+                .to_string()
         );
     }
 }
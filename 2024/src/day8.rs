@@ -1,46 +1,19 @@
+use aoc_common::geometry::Vec2;
+use aoc_common::parse::ParseError;
+use aoc_common::puzzle::Solution;
 use std::collections::{HashMap, HashSet};
-use std::fs;
-use std::ops::{Add, Sub};
-
-#[derive(Eq, Debug, Copy, Clone, PartialEq, Hash)]
-struct Position {
-    x: isize,
-    y: isize,
-}
-
-impl Position {
-    fn new(x: isize, y: isize) -> Position {
-        Position { x, y }
-    }
-}
-
-impl Sub<Position> for Position {
-    type Output = Self;
-
-    fn sub(self, rhs: Position) -> Self::Output {
-        Self::new(self.x - rhs.x, self.y - rhs.y)
-    }
-}
-
-impl Add<Position> for Position {
-    type Output = Self;
-
-    fn add(self, rhs: Position) -> Self::Output {
-        Self::new(self.x + rhs.x, self.y + rhs.y)
-    }
-}
 
 #[derive(Debug)]
-struct Problem {
+pub struct Problem {
     width: usize,
     height: usize,
-    antennas: HashMap<char, Vec<Position>>,
+    antennas: HashMap<char, Vec<Vec2>>,
 }
 
 impl Problem {
     fn from_string(string: &str) -> Self {
         Self {
-            width: string.find("\n").unwrap(),
+            width: string.lines().next().map_or(0, str::len),
             height: string.lines().count(),
             antennas: string
                 .lines()
@@ -50,7 +23,7 @@ impl Problem {
                         .enumerate()
                         .filter_map(move |(x, char)| match char {
                             '.' => None,
-                            _ => Some((char, Position::new(x as isize, y as isize))),
+                            _ => Some((char, Vec2::new(x as i64, y as i64))),
                         })
                 })
                 .fold(HashMap::new(), |mut acc, (char, position)| {
@@ -60,11 +33,19 @@ impl Problem {
         }
     }
 
-    fn in_bounds(&self, position: Position) -> bool {
-        position.x >= 0
-            && position.y >= 0
-            && (position.x as usize) < self.width
-            && (position.y as usize) < self.height
+    fn in_bounds(&self, position: Vec2) -> bool {
+        position
+            .to_position()
+            .is_some_and(|(x, y)| x < self.width && y < self.height)
+    }
+}
+
+impl Solution for Problem {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self::from_string(input))
     }
 
     fn part_1(&self) -> usize {
@@ -83,9 +64,8 @@ impl Problem {
                         })
                     })
             })
-            .collect::<HashSet<Position>>() // Deduplicate antinode positions
-            .iter()
-            .count()
+            .collect::<HashSet<Vec2>>()
+            .len()
     }
 
     fn part_2(&self) -> usize {
@@ -103,67 +83,22 @@ impl Problem {
                             // Extrapolate backward
                             let mut antinode = antenna1;
                             while self.in_bounds(antinode) {
-                                antinodes.push(antinode.clone());
-                                antinode = antinode - delta;
+                                antinodes.push(antinode);
+                                antinode -= delta;
                             }
 
                             // Extrapolate forward
                             antinode = antenna2;
                             while self.in_bounds(antinode) {
-                                antinodes.push(antinode.clone());
-                                antinode = antinode + delta;
+                                antinodes.push(antinode);
+                                antinode += delta;
                             }
 
                             antinodes
                         })
                     })
             })
-            .collect::<HashSet<Position>>() // Deduplicate antinode positions
-            .iter()
-            .count()
-    }
-}
-
-fn main() {
-    let problem = Problem::from_string(
-        fs::read_to_string("input/day8.txt")
-            .expect("Failed to read input")
-            .as_str(),
-    );
-
-    // Attempts: 304 (too high), 291 (too high), 293 (too high), 299 (too high), 280
-    println!("Part 1: {}", problem.part_1());
-    println!("Part 2: {}", problem.part_2()); // Attempt: 958
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE: &str = r#"............
-........0...
-.....0......
-.......0....
-....0.......
-......A.....
-............
-............
-........A...
-.........A..
-............
-............"#;
-
-    #[test]
-    fn test_sample_part_1() {
-        let problem = Problem::from_string(SAMPLE);
-
-        assert_eq!(14, problem.part_1());
-    }
-
-    #[test]
-    fn test_sample_part_2() {
-        let problem = Problem::from_string(SAMPLE);
-
-        assert_eq!(34, problem.part_2());
+            .collect::<HashSet<Vec2>>()
+            .len()
     }
 }
@@ -1,4 +1,5 @@
-use std::fs;
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
 
 #[derive(Debug)]
 struct Filesystem {
@@ -80,67 +81,47 @@ impl Filesystem {
 }
 
 #[derive(Debug)]
-struct Problem {
+pub struct Problem {
     filesystem: Filesystem,
 }
 
 impl Problem {
-    fn from_string(string: &str) -> Self {
-        Self {
-            filesystem: string.lines().nth(0).unwrap().chars().enumerate().fold(
-                Filesystem::new(),
-                |mut acc, (i, char)| {
-                    let size = char.to_digit(10).unwrap() as usize;
-
-                    if i % 2 == 0 {
-                        acc.sectors.append(&mut vec![Some(i as u32 / 2); size])
-                    } else {
-                        acc.sectors.append(&mut vec![None; size]);
-                    }
-
-                    acc
-                },
-            ),
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        let line = string.trim();
+        let mut filesystem = Filesystem::new();
+
+        for (i, (offset, char)) in line.char_indices().enumerate() {
+            let size = char
+                .to_digit(10)
+                .ok_or_else(|| ParseError::at(parse::char_at(line, offset), "Invalid block size"))?
+                as usize;
+
+            if i % 2 == 0 {
+                filesystem
+                    .sectors
+                    .append(&mut vec![Some(i as u32 / 2); size])
+            } else {
+                filesystem.sectors.append(&mut vec![None; size]);
+            }
         }
-    }
 
-    fn part_1(&self) -> usize {
-        self.filesystem.compact_sectors().checksum()
+        Ok(Self { filesystem })
     }
-
-    fn part_2(&self) -> usize {
-        self.filesystem.compact_blocks().checksum()
-    }
-}
-
-fn main() {
-    let problem = Problem::from_string(
-        fs::read_to_string("input/day9.txt")
-            .expect("Failed to read input")
-            .as_str(),
-    );
-
-    println!("Part 1: {}", problem.part_1()); // Attempts: 6211348208140
-    println!("Part 2: {}", problem.part_2()); // Attempts: 6239783302560
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE: &str = "2333133121414131402";
+impl Solution for Problem {
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    #[test]
-    fn test_sample_part_1() {
-        let problem = Problem::from_string(SAMPLE);
-
-        assert_eq!(1928, problem.part_1());
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::from_string(input)
     }
 
-    #[test]
-    fn test_sample_part_2() {
-        let problem = Problem::from_string(SAMPLE);
+    fn part_1(&self) -> usize {
+        self.filesystem.compact_sectors().checksum()
+    }
 
-        assert_eq!(2858, problem.part_2());
+    fn part_2(&self) -> usize {
+        self.filesystem.compact_blocks().checksum()
     }
 }
@@ -0,0 +1,48 @@
+mod day1;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+
+use aoc_common::puzzle::{Day, Year};
+
+pub const YEAR: Year = Year {
+    year: 2024,
+    input_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/input"),
+    answers_path: concat!(env!("CARGO_MANIFEST_DIR"), "/answers.txt"),
+    learnings_path: concat!(env!("CARGO_MANIFEST_DIR"), "/learnings.txt"),
+    days: &[
+        Day::new::<day1::Problem>(1),
+        Day::new::<day2::Problem>(2),
+        Day::new::<day3::Problem>(3),
+        Day::new::<day4::Problem>(4),
+        Day::new::<day5::Problem>(5),
+        Day::new::<day6::Problem>(6),
+        Day::new::<day7::Problem>(7),
+        Day::new::<day8::Problem>(8),
+        Day::new::<day9::Problem>(9),
+        Day::new::<day10::Problem>(10),
+        Day::new::<day11::Problem>(11),
+        Day::new::<day12::Problem>(12),
+        Day::new::<day13::Problem>(13),
+        Day::new::<day14::Problem>(14),
+        Day::new::<day15::Problem>(15),
+        Day::new::<day16::Problem>(16),
+    ],
+};
+
+#[cfg(test)]
+mod fixtures {
+    include!(concat!(env!("OUT_DIR"), "/fixture_tests.rs"));
+}
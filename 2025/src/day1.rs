@@ -1,21 +1,32 @@
-use std::{fmt::Display, fs};
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
+use std::fmt::Display;
 
-struct Problem {
+pub struct Problem {
     safe: Safe,
 }
 
 impl Problem {
-    pub fn from_string(string: &str) -> Self {
-        Self {
-            safe: Safe::from_string(string.trim()),
-        }
+    pub fn from_string(string: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            safe: Safe::from_string(string.trim())?,
+        })
     }
+}
 
-    pub fn part_1(&self) -> u32 {
+impl Solution for Problem {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::from_string(input)
+    }
+
+    fn part_1(&self) -> u32 {
         self.safe.do_rotations().zero_ends
     }
 
-    pub fn part_2(&self) -> u32 {
+    fn part_2(&self) -> u32 {
         self.safe.do_rotations().zero_clicks
     }
 }
@@ -26,11 +37,14 @@ struct Safe {
 }
 
 impl Safe {
-    pub fn from_string(string: &str) -> Self {
-        Self {
+    pub fn from_string(string: &str) -> Result<Self, ParseError> {
+        Ok(Self {
             dial: Dial::default(),
-            rotations: string.lines().map(Rotation::from_string).collect(),
-        }
+            rotations: string
+                .lines()
+                .map(Rotation::from_string)
+                .collect::<Result<_, _>>()?,
+        })
     }
 
     pub fn do_rotations(&self) -> Dial {
@@ -109,11 +123,11 @@ enum Direction {
 }
 
 impl Direction {
-    pub fn from_char(chr: &char) -> Self {
+    pub fn from_char(chr: &char) -> Option<Self> {
         match chr {
-            'L' => Self::Left,
-            'R' => Self::Right,
-            _ => panic!("Unsupported rotation direction"),
+            'L' => Some(Self::Left),
+            'R' => Some(Self::Right),
+            _ => None,
         }
     }
 }
@@ -137,16 +151,18 @@ struct Rotation {
 }
 
 impl Rotation {
-    pub fn from_string(string: &str) -> Self {
+    pub fn from_string(string: &str) -> Result<Self, ParseError> {
         let mut chars = string.chars();
-
-        Self {
-            direction: Direction::from_char(&chars.next().expect("Missing direction letter")),
-            distance: chars
-                .as_str()
-                .parse::<u32>()
-                .expect("Unable to parse rotation distance as unsigned int"),
-        }
+        let direction = chars
+            .next()
+            .ok_or_else(|| ParseError::at(string, "Missing direction letter"))?;
+
+        Ok(Self {
+            direction: Direction::from_char(&direction).ok_or_else(|| {
+                ParseError::at(parse::char_at(string, 0), "Unsupported rotation direction")
+            })?,
+            distance: parse::number(chars.as_str())?,
+        })
     }
 }
 
@@ -155,41 +171,3 @@ impl Display for Rotation {
         write!(f, "{}{}", self.direction, self.distance)
     }
 }
-
-fn main() {
-    let problem = Problem::from_string(
-        fs::read_to_string("input/day1.txt")
-            .expect("Failed to read input")
-            .as_str(),
-    );
-    println!("Part 1: {}", problem.part_1()); // Attempts: 33, 989
-    println!("Part 2: {}", problem.part_2()); // Attempts: 5949, 5941
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE: &str = r#"
-L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82
-"#;
-
-    #[test]
-    fn test_sample_part_1() {
-        assert_eq!(3, Problem::from_string(SAMPLE).part_1());
-    }
-
-    #[test]
-    fn test_sample_part_2() {
-        assert_eq!(6, Problem::from_string(SAMPLE).part_2());
-    }
-}
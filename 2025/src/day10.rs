@@ -0,0 +1,225 @@
+use aoc_common::linear::{self, LinearSystem};
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
+use std::str::FromStr;
+
+pub struct Problem {
+    machines: Vec<Machine>,
+}
+
+impl FromStr for Problem {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            machines: s
+                .trim()
+                .lines()
+                .map(|l| l.parse::<Machine>())
+                .collect::<Result<Vec<Machine>, _>>()?,
+        })
+    }
+}
+
+impl Solution for Problem {
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(&self) -> usize {
+        self.machines
+            .iter()
+            .map(|m| {
+                m.fewest_presses()
+                    .expect("No presses reach the lights")
+                    .len()
+            })
+            .sum()
+    }
+
+    fn part_2(&self) -> i64 {
+        self.machines
+            .iter()
+            .map(|m| {
+                m.fewest_presses_for_joltages()
+                    .expect("No presses reach the joltages")
+            })
+            .sum()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Machine {
+    /// Bit per light, set for the lights that need to be on
+    lights_goal: u64,
+    button_wirings: Vec<Vec<usize>>,
+    joltages: Vec<i64>,
+}
+
+impl FromStr for Machine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // [.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+
+        let (lights_str, right) = parse::split_once(s, "] ")?;
+        let lights_str = lights_str
+            .strip_prefix('[')
+            .ok_or_else(|| ParseError::at(lights_str, "No lights opening bracket found"))?;
+        if lights_str.len() > 64 {
+            return Err(ParseError::at(lights_str, "More than 64 lights"));
+        }
+
+        let (wirings_str, joltages_str) = right
+            .rsplit_once(" ")
+            .ok_or_else(|| ParseError::at(right, "Unable to split button wirings from joltages"))?;
+
+        let joltages = joltages_str
+            .strip_prefix('{')
+            .and_then(|joltages| joltages.strip_suffix('}'))
+            .ok_or_else(|| ParseError::at(joltages_str, "Invalid joltages"))?
+            .split(',')
+            .map(|joltage_str| parse::token(joltage_str, "joltage"))
+            .collect::<Result<Vec<i64>, _>>()?;
+        if joltages.len() != lights_str.len() {
+            return Err(ParseError::at(joltages_str, "Expected a joltage per light"));
+        }
+
+        let button_wirings = wirings_str
+            .split_whitespace()
+            .map(|wiring_str| {
+                wiring_str
+                    .strip_prefix('(')
+                    .and_then(|wiring| wiring.strip_suffix(')'))
+                    .ok_or_else(|| ParseError::at(wiring_str, "Invalid wiring"))?
+                    .split(',')
+                    .map(|button_str| {
+                        let light = parse::token(button_str, "button number in wiring")?;
+                        match light < lights_str.len() {
+                            true => Ok(light),
+                            false => Err(ParseError::at(button_str, "No such light")),
+                        }
+                    })
+                    .collect::<Result<Vec<usize>, _>>()
+            })
+            .collect::<Result<Vec<Vec<usize>>, _>>()?;
+        if button_wirings.len() > 64 {
+            return Err(ParseError::at(wirings_str, "More than 64 buttons"));
+        }
+
+        Ok(Self {
+            lights_goal: lights_str
+                .chars()
+                .rev()
+                .fold(0, |acc, c| acc << 1 | (c == '#') as u64),
+            button_wirings,
+            joltages,
+        })
+    }
+}
+
+impl Machine {
+    /// Buttons to press once each to turn on the lights of the goal, as few as possible.
+    /// Pressing a button twice undoes it, so every button is pressed at most once and the
+    /// presses are a combination of buttons whose toggled lights XOR to the goal.
+    fn fewest_presses(&self) -> Option<Vec<usize>> {
+        let buttons = self
+            .button_wirings
+            .iter()
+            .map(|wiring| wiring.iter().fold(0, |acc, light| acc | 1 << light))
+            .collect::<Vec<u64>>();
+
+        linear::min_xor_combination(&buttons, self.lights_goal)
+    }
+
+    /// Every press of a button increases the joltage of the counters it is wired to by one. The
+    /// number of presses of each button solve a linear system with an equation per counter.
+    /// Elimination leaves a few free buttons, each of which can't be pressed more often than
+    /// the lowest joltage it adds to, so all their press counts can be tried.
+    fn fewest_presses_for_joltages(&self) -> Option<i64> {
+        let coefficients = (0..self.joltages.len())
+            .map(|counter| {
+                self.button_wirings
+                    .iter()
+                    .map(|wiring| wiring.contains(&counter) as i64)
+                    .collect()
+            })
+            .collect::<Vec<_>>();
+        let system = LinearSystem::reduce(&coefficients, &self.joltages)?;
+
+        let bounds = system
+            .free_variables()
+            .iter()
+            .map(|&button| {
+                self.button_wirings[button]
+                    .iter()
+                    .map(|&counter| self.joltages[counter])
+                    .min()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+
+        system.minimum_non_negative_sum(&bounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixture;
+
+    #[test]
+    fn test_fewest_presses() {
+        let problem = fixture!("day10").input.parse::<Problem>().unwrap();
+
+        for (machine, presses) in problem.machines.iter().zip([2, 3, 2]) {
+            let buttons = machine.fewest_presses().unwrap();
+            let lights = buttons.iter().fold(0, |acc, &button| {
+                machine.button_wirings[button]
+                    .iter()
+                    .fold(acc, |acc, light| acc ^ 1 << light)
+            });
+
+            assert_eq!(presses, buttons.len());
+            assert_eq!(machine.lights_goal, lights);
+        }
+
+        // The third machine only turns on with its second and third button
+        assert_eq!(Some(vec![1, 2]), problem.machines[2].fewest_presses());
+    }
+
+    #[test]
+    fn test_fewest_presses_for_joltages() {
+        let problem = fixture!("day10").input.parse::<Problem>().unwrap();
+
+        assert_eq!(
+            vec![Some(10), Some(12), Some(11)],
+            problem
+                .machines
+                .iter()
+                .map(|machine| machine.fewest_presses_for_joltages())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!("[.#] (0) (2) {1,2}".parse::<Machine>().is_err());
+        assert!("[.#] (0) (1) {1,2,3}".parse::<Machine>().is_err());
+
+        // The joltage solver looks up the joltage of every light a button is wired to
+        assert!("[.#] (0,1) {1}".parse::<Machine>().is_err());
+        assert!("[.#] (0,2) {1,2}".parse::<Machine>().is_err());
+
+        let buttons = vec!["(0)"; 65].join(" ");
+        assert!(format!("[#] {buttons} {{1}}").parse::<Machine>().is_err());
+        assert!(
+            format!("[#] {} {{1}}", &buttons[4..])
+                .parse::<Machine>()
+                .is_ok()
+        );
+    }
+}
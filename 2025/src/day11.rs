@@ -1,17 +1,18 @@
+use aoc_common::combinator::{Parser, lines, separated, separated_pair, word};
+use aoc_common::parse::ParseError;
+use aoc_common::puzzle::{Maybe, Solution};
 use std::{
     collections::{HashMap, HashSet},
-    error::Error,
-    fs,
     str::FromStr,
     vec,
 };
 
-struct Problem {
+pub struct Problem {
     schematic: Schematic,
 }
 
 impl FromStr for Problem {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
@@ -20,30 +21,22 @@ impl FromStr for Problem {
     }
 }
 
-impl Problem {
-    pub fn part_1(&self) -> usize {
-        self.schematic.count_paths(
-            *self.schematic.devices.get("you").expect("Expect you node"),
-            *self.schematic.devices.get("out").expect("Expect out node"),
-            vec![],
-            HashSet::new(),
-            &mut HashMap::new(),
-        )
+impl Solution for Problem {
+    type Answer1 = Maybe<usize>;
+    type Answer2 = Maybe<usize>;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        input.parse()
     }
 
-    pub fn part_2(&self) -> usize {
-        self.schematic.count_paths(
-            *self.schematic.devices.get("svr").expect("Expect svr node"),
-            *self.schematic.devices.get("out").expect("Expect out node"),
-            vec![],
-            vec![
-                *self.schematic.devices.get("dac").expect("Expect dac node"),
-                *self.schematic.devices.get("fft").expect("Expect fft node"),
-            ]
-            .into_iter()
-            .collect(),
-            &mut HashMap::new(),
-        )
+    /// No answer without a `you` device, like for the sample of part 2
+    fn part_1(&self) -> Maybe<usize> {
+        self.schematic.paths("you", "out", &[]).into()
+    }
+
+    /// No answer without the `svr`, `dac` and `fft` devices, like for the sample of part 1
+    fn part_2(&self) -> Maybe<usize> {
+        self.schematic.paths("svr", "out", &["dac", "fft"]).into()
     }
 }
 
@@ -56,26 +49,62 @@ struct Schematic {
 }
 
 impl FromStr for Schematic {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut instance = Self::default();
 
-        s.lines().for_each(|l| {
-            let (input_str, outputs_str) = l.split_once(": ").expect("Expected semicolon");
+        // Lines like `aaa: you hhh`
+        let device = separated_pair(word(), ": ", separated(word(), " "));
+        let devices = lines(device).parse_all(s)?;
+
+        // Every device but the one of the reactor lists its outputs, and some lead to the reactor
+        let listed = devices
+            .iter()
+            .map(|&(input_str, _)| input_str)
+            .collect::<HashSet<_>>();
+        let mut outputs = devices.iter().flat_map(|(_, output_strs)| output_strs);
+        if let Some(output_str) = outputs
+            .clone()
+            .find(|&&output_str| output_str != "out" && !listed.contains(output_str))
+        {
+            return Err(ParseError::at(output_str, "Unknown device"));
+        }
+        if !outputs.any(|&output_str| output_str == "out") {
+            return Err(ParseError::after(s, "No device leads to out"));
+        }
+
+        for (input_str, output_strs) in devices {
             let input_idx = instance.register_device(input_str.to_string());
-            let output_idxs = outputs_str
-                .split_whitespace()
+            let output_idxs = output_strs
+                .into_iter()
                 .map(|output_label| instance.register_device(output_label.to_string()))
                 .collect();
             instance.mappings.insert(input_idx, output_idxs);
-        });
+        }
 
         Ok(instance)
     }
 }
 
 impl Schematic {
+    /// Number of paths between the devices with the given labels that pass all the devices to
+    /// visit, unless one of the devices doesn't exist
+    fn paths(&self, from: &str, to: &str, to_visit: &[&str]) -> Option<usize> {
+        Some(
+            self.count_paths(
+                *self.devices.get(from)?,
+                *self.devices.get(to)?,
+                vec![],
+                to_visit
+                    .iter()
+                    .map(|label| self.devices.get(*label).copied())
+                    .collect::<Option<_>>()?,
+                &mut HashMap::new(),
+            ),
+        )
+    }
+
     // For debugging purposes
     fn _path_str(&self, path: &[usize]) -> String {
         path.iter()
@@ -153,56 +182,3 @@ impl Schematic {
         device_idx
     }
 }
-
-fn main() -> Result<(), Box<dyn Error>> {
-    let problem = fs::read_to_string("input/day11.txt")?.parse::<Problem>()?;
-
-    println!("Part 1: {}", problem.part_1()); // 428
-    println!("Part 2: {}", problem.part_2()); // 331468292364745
-
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE: &str = r#"
-aaa: you hhh
-you: bbb ccc
-bbb: ddd eee
-ccc: ddd eee fff
-ddd: ggg
-eee: out
-fff: out
-ggg: out
-hhh: ccc fff iii
-iii: out
-"#;
-
-    const SAMPLE2: &str = r#"
-svr: aaa bbb
-aaa: fft
-fft: ccc
-bbb: tty
-tty: ccc
-ccc: ddd eee
-ddd: hub
-hub: fff
-eee: dac
-dac: fff
-fff: ggg hhh
-ggg: out
-hhh: out
-"#;
-
-    #[test]
-    fn test_sample_part_1() {
-        assert_eq!(5, SAMPLE.parse::<Problem>().unwrap().part_1());
-    }
-
-    #[test]
-    fn test_sample_part_2() {
-        assert_eq!(2, SAMPLE2.parse::<Problem>().unwrap().part_2());
-    }
-}
@@ -0,0 +1,124 @@
+use aoc_common::combinator::{Parser, integer, lines, separated, separated_pair};
+use aoc_common::grid::Grid;
+use aoc_common::parse::{self, ParseError};
+use aoc_common::polyomino::{self, Polyomino};
+use aoc_common::puzzle::{Solution, Unsolved};
+use std::str::FromStr;
+
+pub struct Problem {
+    presents: Vec<Polyomino>,
+    regions: Vec<Region>,
+}
+
+impl Solution for Problem {
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(&self) -> usize {
+        self.regions
+            .iter()
+            .filter(|region| region.fits(&self.presents))
+            .count()
+    }
+
+    fn part_2(&self) -> Self::Answer2 {
+        Unsolved
+    }
+}
+
+impl FromStr for Problem {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Sections of presents like `0:\n###\n##.\n##.`, followed by the regions
+        let mut sections = s.trim().split("\n\n").collect::<Vec<_>>();
+        let regions_str = sections
+            .pop()
+            .ok_or_else(|| ParseError::after(s, "No regions found"))?;
+
+        let presents = sections
+            .into_iter()
+            .map(|section| {
+                let (_, shape) = parse::split_once(section, ":\n")?;
+                let present = Polyomino::new(&Grid::parse_with(shape, |c| match c {
+                    '#' => Ok(true),
+                    '.' => Ok(false),
+                    _ => Err("Unknown shape cell"),
+                })?);
+
+                match present.area() {
+                    0 => Err(ParseError::at(shape, "Empty present")),
+                    _ => Ok(present),
+                }
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        let region = separated_pair(
+            separated_pair(integer(), "x", integer()),
+            ": ",
+            separated(integer(), " "),
+        )
+        .map(|((width, height), counts)| Region {
+            width,
+            height,
+            counts,
+        });
+        let regions = lines(region).parse_all(regions_str)?;
+
+        if let Some(line) = regions_str
+            .lines()
+            .zip(&regions)
+            .find(|(_, region)| region.counts.len() != presents.len())
+            .map(|(line, _)| line)
+        {
+            return Err(ParseError::at(line, "Expected a count per present"));
+        }
+
+        Ok(Self { presents, regions })
+    }
+}
+
+/// Area under a tree, with the number of each present that has to fit in it
+struct Region {
+    width: usize,
+    height: usize,
+    counts: Vec<usize>,
+}
+
+impl Region {
+    fn fits(&self, presents: &[Polyomino]) -> bool {
+        let presents = presents
+            .iter()
+            .zip(self.counts.iter().copied())
+            .collect::<Vec<_>>();
+
+        polyomino::pack(self.width, self.height, &presents).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixture;
+
+    #[test]
+    fn test_regions() {
+        let problem = fixture!("day12").input.parse::<Problem>().unwrap();
+
+        assert_eq!(6, problem.presents.len());
+        assert_eq!(
+            vec![true, true, false],
+            problem
+                .regions
+                .iter()
+                .map(|region| region.fits(&problem.presents))
+                .collect::<Vec<_>>()
+        );
+        assert!("0:\n#\n\n2x2: 1 1".parse::<Problem>().is_err());
+        assert!("0:\n..\n\n2x2: 1".parse::<Problem>().is_err());
+    }
+}
@@ -1,18 +1,28 @@
-use std::{fs, str::FromStr};
+use aoc_common::combinator::{Parser, integer, separated_pair};
+use aoc_common::parse::ParseError;
+use aoc_common::puzzle::Solution;
+use std::str::FromStr;
 
-struct Problem {
+pub struct Problem {
     ranges: Vec<Range>,
 }
 
-impl Problem {
-    pub fn part_1(&self) -> u64 {
+impl Solution for Problem {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(&self) -> u64 {
         self.ranges
             .iter()
             .map(|range| range.sum_invalid_ids(Some(2)))
             .sum()
     }
 
-    pub fn part_2(&self) -> u64 {
+    fn part_2(&self) -> u64 {
         self.ranges
             .iter()
             .map(|range| range.sum_invalid_ids(None))
@@ -21,7 +31,7 @@ impl Problem {
 }
 
 impl FromStr for Problem {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
@@ -40,20 +50,12 @@ struct Range {
 }
 
 impl FromStr for Range {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.trim().split_once('-') {
-            Some((from, to)) => Ok(Self {
-                from: from
-                    .parse::<u64>()
-                    .map_err(|_| format!("Invalid range start: {}", from))?,
-                to: to
-                    .parse::<u64>()
-                    .map_err(|_| format!("Invalid range end: {}", to))?,
-            }),
-            None => Err("Missing hyphen in range".to_string()),
-        }
+        separated_pair(integer(), "-", integer())
+            .map(|(from, to)| Self { from, to })
+            .parse_all(s.trim())
     }
 }
 
@@ -89,32 +91,3 @@ impl Range {
         sum
     }
 }
-
-fn main() {
-    let problem = fs::read_to_string("input/day2.txt")
-        .expect("Failed to read input")
-        .parse::<Problem>()
-        .unwrap();
-
-    println!("Part 1: {}", problem.part_1()); // Attempts: 20223751480 
-    println!("Part 2: {}", problem.part_2()); // Attempts: 30260171216 
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE: &str = r#"
-11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124
-"#;
-
-    #[test]
-    fn test_sample_part_1() {
-        assert_eq!(1227775554, SAMPLE.parse::<Problem>().unwrap().part_1());
-    }
-
-    #[test]
-    fn test_sample_part_2() {
-        assert_eq!(4174379265, SAMPLE.parse::<Problem>().unwrap().part_2());
-    }
-}
@@ -1,27 +1,39 @@
-use std::{fs, str::FromStr};
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
+use std::str::FromStr;
 
-struct Problem {
+/// Batteries to turn on in every bank for part 2, which is the most any part needs
+const MAX_BATTERIES_ON: usize = 12;
+
+pub struct Problem {
     battery_banks: Vec<BatteryBank>,
 }
 
-impl Problem {
-    pub fn part_1(&self) -> u64 {
+impl Solution for Problem {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(&self) -> u64 {
         self.battery_banks
             .iter()
             .map(|bank| bank.largest_joltage_n(2))
             .sum()
     }
 
-    pub fn part_2(&self) -> u64 {
+    fn part_2(&self) -> u64 {
         self.battery_banks
             .iter()
-            .map(|bank| bank.largest_joltage_n(12))
+            .map(|bank| bank.largest_joltage_n(MAX_BATTERIES_ON))
             .sum()
     }
 }
 
 impl FromStr for Problem {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
@@ -39,16 +51,23 @@ struct BatteryBank {
 }
 
 impl FromStr for BatteryBank {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            batteries: s
-                .trim()
-                .chars()
-                .map(|char| char.to_string().parse::<Battery>())
-                .collect::<Result<Vec<Battery>, _>>()?,
-        })
+        let s = s.trim();
+        let batteries = s
+            .char_indices()
+            .map(|(i, _)| parse::char_at(s, i).parse::<Battery>())
+            .collect::<Result<Vec<Battery>, _>>()?;
+
+        if batteries.len() < MAX_BATTERIES_ON {
+            return Err(ParseError::at(
+                s,
+                format!("Expected at least {} batteries", MAX_BATTERIES_ON),
+            ));
+        }
+
+        Ok(Self { batteries })
     }
 }
 
@@ -121,7 +140,7 @@ struct Battery {
 }
 
 impl FromStr for Battery {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
@@ -129,41 +148,9 @@ impl FromStr for Battery {
                 .trim()
                 .chars()
                 .nth(0)
-                .ok_or("No joltage value found")?
+                .ok_or_else(|| ParseError::at(s, "No joltage value found"))?
                 .to_digit(10)
-                .ok_or("Invalid joltage value given")?,
+                .ok_or_else(|| ParseError::at(s, "Invalid joltage value given"))?,
         })
     }
 }
-
-fn main() {
-    let problem = fs::read_to_string("input/day3.txt")
-        .expect("Failed to read input")
-        .parse::<Problem>()
-        .unwrap();
-
-    println!("Part 1: {}", problem.part_1()); // Attempts: 17330, 17493
-    println!("Part 2: {}", problem.part_2()); // Attempts: 173685428989126
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE: &str = r#"
-987654321111111
-811111111111119
-234234234234278
-818181911112111
-"#;
-
-    #[test]
-    fn test_sample_part_1() {
-        assert_eq!(357, SAMPLE.parse::<Problem>().unwrap().part_1());
-    }
-
-    #[test]
-    fn test_sample_part_2() {
-        assert_eq!(3121910778619, SAMPLE.parse::<Problem>().unwrap().part_2());
-    }
-}
@@ -0,0 +1,156 @@
+use aoc_common::grid::{Grid, Position};
+use aoc_common::parse::ParseError;
+use aoc_common::puzzle::Solution;
+use aoc_common::render::{Frame, Rgb};
+use std::str::FromStr;
+
+pub struct Problem {
+    diagram: Diagram,
+}
+
+impl Solution for Problem {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(&self) -> usize {
+        self.diagram.accessible_rolls()
+    }
+
+    fn part_2(&self) -> usize {
+        self.diagram.accessible_rolls_recursive(|_, _| ())
+    }
+
+    /// The rolls before every wave of removals, with the ones about to be removed in red
+    fn render(&self, frame: &mut dyn FnMut(Frame)) {
+        self.diagram.accessible_rolls_recursive(|diagram, locs| {
+            let mut image = diagram.frame();
+            for &loc in locs {
+                image[loc] = Rgb::RED;
+            }
+            frame(image);
+        });
+    }
+}
+
+impl FromStr for Problem {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            diagram: s.parse()?,
+        })
+    }
+}
+
+#[derive(Clone)]
+struct Diagram {
+    // true for locations holding a paper roll
+    grid: Grid<bool>,
+}
+
+impl FromStr for Diagram {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            grid: Grid::parse_with(s, |c| match c {
+                '@' => Ok(true),
+                '.' => Ok(false),
+                _ => Err("Unknown location"),
+            })?,
+        })
+    }
+}
+
+impl Diagram {
+    fn accessible_roll_locs(&self) -> Vec<Position> {
+        self.grid
+            .iter()
+            .filter(|(_, has_paper)| **has_paper) // Only locations with paper rolls
+            .map(|(loc, _)| loc)
+            .filter(|loc| {
+                // Only take locations that have less than four adjacent paper rolls
+                self.grid
+                    .neighbours_8(*loc)
+                    .filter(|loc| self.grid[*loc])
+                    .count()
+                    < 4
+            })
+            .collect()
+    }
+
+    fn accessible_rolls(&self) -> usize {
+        self.accessible_roll_locs().len()
+    }
+
+    /// Keeps removing accessible rolls until none are left. `on_wave` is called with the diagram
+    /// before every wave and the rolls it removes, lastly with the rolls that are left over.
+    fn accessible_rolls_recursive(&self, mut on_wave: impl FnMut(&Self, &[Position])) -> usize {
+        let mut diagram = self.clone();
+        let mut accessible_rolls = 0;
+
+        loop {
+            let locs = diagram.accessible_roll_locs();
+            on_wave(&diagram, &locs);
+
+            if locs.is_empty() {
+                break;
+            }
+
+            accessible_rolls += locs.len();
+
+            // Remove all accessible paper rolls
+            for loc in locs {
+                diagram.grid[loc] = false;
+            }
+        }
+
+        accessible_rolls
+    }
+
+    fn frame(&self) -> Frame {
+        self.grid.map(|&has_paper| match has_paper {
+            true => Rgb::WHITE,
+            false => Rgb::BLACK,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixture;
+
+    #[test]
+    fn test_diagram() {
+        let diagram = fixture!("day4").input.parse::<Problem>().unwrap().diagram;
+
+        assert_eq!(10, diagram.grid.width());
+        assert_eq!(10, diagram.grid.height());
+
+        assert_eq!(
+            vec![(1, 0), (0, 1), (1, 1)],
+            diagram.grid.neighbours_8((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(8, 8), (9, 8), (8, 9)],
+            diagram.grid.neighbours_8((9, 9)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_waves() {
+        let diagram = fixture!("day4").input.parse::<Problem>().unwrap().diagram;
+        let mut waves = vec![];
+
+        assert_eq!(
+            43,
+            diagram.accessible_rolls_recursive(|_, locs| waves.push(locs.len()))
+        );
+        assert_eq!(vec![13, 12, 7, 5, 2, 1, 1, 1, 1, 0], waves);
+    }
+}
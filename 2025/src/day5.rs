@@ -1,11 +1,14 @@
-use std::{error::Error, fs, str::FromStr};
+use aoc_common::combinator::{Parser, integer, lines, separated_pair};
+use aoc_common::parse::ParseError;
+use aoc_common::puzzle::Solution;
+use std::str::FromStr;
 
-struct Problem {
+pub struct Problem {
     inventory: Inventory,
 }
 
 impl FromStr for Problem {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
@@ -14,12 +17,19 @@ impl FromStr for Problem {
     }
 }
 
-impl Problem {
-    pub fn part_1(&self) -> usize {
+impl Solution for Problem {
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(&self) -> usize {
         self.inventory.fresh_available_ingredients()
     }
 
-    pub fn part_2(&self) -> u64 {
+    fn part_2(&self) -> u64 {
         self.inventory.fresh_ingredients()
     }
 }
@@ -91,76 +101,16 @@ impl Inventory {
 }
 
 impl FromStr for Inventory {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (range_lines, ingredient_lines) = s
-            .trim()
-            .split_once("\n\n")
-            .ok_or("Missing blank line in input")?;
+        let range = separated_pair(integer(), "-", integer());
+        let (ingredient_ranges, available_ingredients) =
+            separated_pair(lines(range), "\n\n", lines(integer())).parse_all(s.trim())?;
 
         Ok(Self {
-            ingredient_ranges: range_lines
-                .lines()
-                .map(|range_line| {
-                    let (start, end) = range_line
-                        .split_once('-')
-                        .ok_or("Missing range separator")?;
-
-                    Ok((
-                        start
-                            .parse::<u64>()
-                            .map_err(|_| "Range start is not an integer")?,
-                        end.parse::<u64>()
-                            .map_err(|_| "Range end is not an integer")?,
-                    ))
-                })
-                .collect::<Result<Vec<(u64, u64)>, String>>()?,
-            available_ingredients: ingredient_lines
-                .lines()
-                .map(|line| {
-                    line.parse::<u64>()
-                        .map_err(|_| "Ingredient is not an integer")
-                })
-                .collect::<Result<Vec<u64>, _>>()?,
+            ingredient_ranges,
+            available_ingredients,
         })
     }
 }
-
-fn main() -> Result<(), Box<dyn Error>> {
-    let problem = fs::read_to_string("input/day5.txt")?.parse::<Problem>()?;
-
-    println!("Part 1: {}", problem.part_1()); // Attempts: 739
-    println!("Part 2: {}", problem.part_2()); // Attempts: 355636767906941 (too high), 344486348901788
-
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE: &str = r#"
-3-5
-10-14
-16-20
-12-18
-
-1
-5
-8
-11
-17
-32
-"#;
-
-    #[test]
-    fn test_sample_part_1() {
-        assert_eq!(3, SAMPLE.parse::<Problem>().unwrap().part_1());
-    }
-
-    #[test]
-    fn test_sample_part_2() {
-        assert_eq!(14, SAMPLE.parse::<Problem>().unwrap().part_2());
-    }
-}
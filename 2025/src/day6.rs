@@ -1,11 +1,13 @@
-use std::{error::Error, fs, str::FromStr, vec};
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
+use std::{str::FromStr, vec};
 
-struct Problem {
+pub struct Problem {
     homework: Homework,
 }
 
 impl FromStr for Problem {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
@@ -14,12 +16,19 @@ impl FromStr for Problem {
     }
 }
 
-impl Problem {
-    pub fn part_1(&self) -> u64 {
+impl Solution for Problem {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(&self) -> u64 {
         self.homework.grand_total()
     }
 
-    pub fn part_2(&self) -> u64 {
+    fn part_2(&self) -> u64 {
         self.homework.grand_total_rtl()
     }
 }
@@ -31,17 +40,31 @@ struct Homework {
 }
 
 impl FromStr for Homework {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (numbers, operators) = s.rsplit_once("\n").ok_or("No last line")?;
+        let (numbers, operators) = s
+            .rsplit_once("\n")
+            .ok_or_else(|| ParseError::after(s, "Missing line of operators"))?;
+        let operators = operators
+            .split_whitespace()
+            .map(|operator| operator.parse::<Operator>())
+            .collect::<Result<Vec<Operator>, _>>()?;
+
+        // Every row needs a number for every operator
+        if let Some(line) = numbers
+            .lines()
+            .find(|line| line.split_whitespace().count() != operators.len())
+        {
+            return Err(ParseError::at(
+                line,
+                format!("Expected {} numbers", operators.len()),
+            ));
+        }
 
         Ok(Self {
             matrix: numbers.parse::<Matrix>()?,
-            operators: operators
-                .split_whitespace()
-                .map(|operator| operator.parse::<Operator>())
-                .collect::<Result<Vec<Operator>, _>>()?,
+            operators,
             worksheet: s.to_string(),
         })
     }
@@ -114,14 +137,23 @@ impl Operator {
 }
 
 impl FromStr for Operator {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s.chars().nth(0).ok_or("Expected operator char")? {
-            '+' => Self::Add,
-            '*' => Self::Mul,
-            c => Err(format!("Unexpected operator char: {}", c))?,
-        })
+        Ok(
+            match s
+                .chars()
+                .nth(0)
+                .ok_or_else(|| ParseError::at(s, "Expected operator char"))?
+            {
+                '+' => Self::Add,
+                '*' => Self::Mul,
+                _ => Err(ParseError::at(
+                    parse::char_at(s, 0),
+                    "Unexpected operator char",
+                ))?,
+            },
+        )
     }
 }
 
@@ -136,59 +168,34 @@ impl Matrix {
 }
 
 impl FromStr for Matrix {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             rows: s
                 .lines()
-                .map(|l| {
-                    Ok::<Vec<u64>, String>(
-                        // TODO: split on specific index and preserve whitespace for aligntment
-                        // also represent each number as string (or right pad with zeroes)
-                        l.split_whitespace()
-                            .map(|number| number.parse::<u64>().map_err(|_| "Expected number"))
-                            .collect::<Result<Vec<u64>, _>>()?,
-                    )
-                })
+                // TODO: split on specific index and preserve whitespace for aligntment
+                // also represent each number as string (or right pad with zeroes)
+                .map(parse::numbers)
                 .collect::<Result<Vec<Vec<u64>>, _>>()?,
         })
     }
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let problem = fs::read_to_string("input/day6.txt")?.parse::<Problem>()?;
-
-    println!("Part 1: {}", problem.part_1()); // Attempts: 4719804927602
-    println!("Part 2: {}", problem.part_2()); // Attempts: 9608327000261
-
-    Ok(())
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::fixture;
 
-    const SAMPLE: &str = r#"
-123 328  51 64 
- 45 64  387 23 
-  6 98  215 314
-*   +   *   +
-"#;
     #[test]
     fn test_matrix() {
-        let matrix = SAMPLE.parse::<Problem>().unwrap().homework.matrix;
+        let matrix = fixture!("day6")
+            .input
+            .parse::<Problem>()
+            .unwrap()
+            .homework
+            .matrix;
 
         assert_eq!(vec![64, 23, 314], matrix.column(3));
     }
-
-    #[test]
-    fn test_sample_part_1() {
-        assert_eq!(4277556, SAMPLE.parse::<Problem>().unwrap().part_1());
-    }
-
-    #[test]
-    fn test_sample_part_2() {
-        assert_eq!(3263827, SAMPLE.parse::<Problem>().unwrap().part_2());
-    }
 }
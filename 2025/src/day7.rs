@@ -1,11 +1,14 @@
-use std::{collections::HashMap, error::Error, fs, str::FromStr, vec};
+use aoc_common::geometry::Vec2;
+use aoc_common::parse::ParseError;
+use aoc_common::puzzle::Solution;
+use std::{collections::HashMap, str::FromStr, vec};
 
-struct Problem {
+pub struct Problem {
     diagram: Diagram,
 }
 
 impl FromStr for Problem {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
@@ -14,38 +17,46 @@ impl FromStr for Problem {
     }
 }
 
-impl Problem {
-    pub fn part_1(&self) -> usize {
+impl Solution for Problem {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(&self) -> usize {
         self.diagram.count_splits()
     }
 
-    pub fn part_2(&self) -> usize {
+    fn part_2(&self) -> usize {
         self.diagram
             .count_timelines(self.diagram.start, &mut HashMap::new())
     }
 }
 
-// Location represented by tuple of Cartesian coordinates (x,y)
-type Location = (usize, usize);
-
 struct Diagram {
-    start: Location,
-    splitters: Vec<Location>,
-    width: usize,
-    height: usize,
+    start: Vec2,
+    splitters: Vec<Vec2>,
+    width: i64,
+    height: i64,
 }
 
 impl FromStr for Diagram {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let first_line = s
+            .lines()
+            .nth(0)
+            .ok_or_else(|| ParseError::at(s, "No first line"))?;
+
         Ok(Self {
-            start: (
-                s.lines()
-                    .nth(0)
-                    .ok_or("No first line")?
+            start: Vec2::new(
+                first_line
                     .find("S")
-                    .ok_or("No start position")?,
+                    .ok_or_else(|| ParseError::at(first_line, "No start position"))?
+                    as i64,
                 0,
             ),
             splitters: s
@@ -56,47 +67,43 @@ impl FromStr for Diagram {
                     l.chars()
                         .enumerate()
                         .filter_map(|(i, c)| match c {
-                            '^' => Some((i, j)),
+                            '^' => Some(Vec2::new(i as i64, j as i64)),
                             _ => None,
                         })
-                        .collect::<Vec<Location>>()
+                        .collect::<Vec<Vec2>>()
                 })
                 .collect(),
-            width: s.find('\n').ok_or("No newline found")?,
-            height: s.lines().count(),
+            width: first_line.len() as i64,
+            height: s.lines().count() as i64,
         })
     }
 }
 
 impl Diagram {
-    fn count_timelines(
-        &self,
-        loc_particle: Location,
-        loc_counts: &mut HashMap<Location, usize>,
-    ) -> usize {
+    fn count_timelines(&self, loc_particle: Vec2, loc_counts: &mut HashMap<Vec2, usize>) -> usize {
         // Check the cache to prevent computing the timelines for this particle position again
         if let Some(timeline_count) = loc_counts.get(&loc_particle) {
             return *timeline_count;
         }
 
-        for j in loc_particle.1..self.height {
+        for j in loc_particle.y..self.height {
             // Find splitter hit (if any) on current row, otherwise go to next row
             match self
                 .row_splitter_xs(j)
                 .into_iter()
-                .find(|splitter_x| *splitter_x == loc_particle.0)
+                .find(|splitter_x| *splitter_x == loc_particle.x)
             {
                 // Splitter hit by the particle, count timelines on traversable branches
                 Some(_) => {
                     let mut timeline_count = 0;
 
-                    if loc_particle.0.checked_sub(1).is_some() {
+                    if loc_particle.x > 0 {
                         timeline_count +=
-                            self.count_timelines((loc_particle.0 - 1, j + 1), loc_counts)
+                            self.count_timelines(Vec2::new(loc_particle.x - 1, j + 1), loc_counts)
                     }
-                    if loc_particle.0 + 1 < self.width {
+                    if loc_particle.x + 1 < self.width {
                         timeline_count +=
-                            self.count_timelines((loc_particle.0 + 1, j + 1), loc_counts)
+                            self.count_timelines(Vec2::new(loc_particle.x + 1, j + 1), loc_counts)
                     }
 
                     // Cache the number of timelines for this particle position
@@ -114,7 +121,7 @@ impl Diagram {
     }
 
     fn count_splits(&self) -> usize {
-        let mut beam_xs = vec![self.start.0];
+        let mut beam_xs = vec![self.start.x];
         let mut split_count = 0;
 
         for j in 1..self.height {
@@ -136,7 +143,7 @@ impl Diagram {
 
                     // Split this beam into 2 new beams (or 1 if near edge)
                     let mut new_beam_xs = Vec::with_capacity(2);
-                    if beam_x.checked_sub(1).is_some() {
+                    if *beam_x > 0 {
                         new_beam_xs.push(beam_x - 1);
                     }
                     if beam_x + 1 < self.width {
@@ -144,7 +151,7 @@ impl Diagram {
                     }
                     new_beam_xs
                 })
-                .collect::<Vec<usize>>();
+                .collect::<Vec<i64>>();
 
             // Deduplicate splits into same column
             beam_xs.sort();
@@ -154,55 +161,12 @@ impl Diagram {
         split_count
     }
 
-    fn row_splitter_xs(&self, j: usize) -> Vec<usize> {
+    fn row_splitter_xs(&self, j: i64) -> Vec<i64> {
         self.splitters
             .iter()
             .copied()
-            .filter(|loc| loc.1 == j)
-            .map(|loc| loc.0)
-            .collect::<Vec<usize>>()
-    }
-}
-
-fn main() -> Result<(), Box<dyn Error>> {
-    let problem = fs::read_to_string("input/day7.txt")?.parse::<Problem>()?;
-
-    println!("Part 1: {}", problem.part_1()); // Attempts: 1622
-    println!("Part 2: {}", problem.part_2()); // Attempts: 10357305916520
-
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE: &str = r#"
-.......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............
-"#;
-
-    #[test]
-    fn test_sample_part_1() {
-        assert_eq!(21, SAMPLE.parse::<Problem>().unwrap().part_1());
-    }
-
-    #[test]
-    fn test_sample_part_2() {
-        assert_eq!(40, SAMPLE.parse::<Problem>().unwrap().part_2());
+            .filter(|loc| loc.y == j)
+            .map(|loc| loc.x)
+            .collect::<Vec<i64>>()
     }
 }
@@ -1,25 +1,50 @@
-use std::{collections::HashMap, error::Error, fmt::Display, fs, ops::Sub, str::FromStr, vec};
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::Solution;
+use std::{collections::HashMap, fmt::Display, ops::Sub, str::FromStr, vec};
 
-struct Problem {
+pub struct Problem {
     playground: Playground,
+    /// Number of shortest connections to make in part 1
+    connections: usize,
 }
 
 impl FromStr for Problem {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             playground: s.trim().parse()?,
+            connections: 1000,
         })
     }
 }
 
-impl Problem {
-    pub fn part_1(&self, n: usize) -> usize {
-        self.playground.product_largest_3_circuits(n)
+impl Solution for Problem {
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        input.parse()
     }
 
-    pub fn part_2(&self) -> u64 {
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "connections" => {
+                self.connections = value
+                    .parse()
+                    .map_err(|_| format!("Invalid connections: {}", value))?
+            }
+            _ => return Err(format!("Unknown parameter: {}", name)),
+        }
+
+        Ok(())
+    }
+
+    fn part_1(&self) -> usize {
+        self.playground.product_largest_3_circuits(self.connections)
+    }
+
+    fn part_2(&self) -> u64 {
         self.playground.wall_dist()
     }
 }
@@ -29,16 +54,13 @@ struct Playground {
 }
 
 impl FromStr for Playground {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             boxes: s
                 .lines()
-                .map(|l| {
-                    l.parse::<Coordinate>()
-                        .map_err(|_| "Failed to parse coordinate")
-                })
+                .map(|l| l.parse::<Coordinate>())
                 .collect::<Result<Vec<Coordinate>, _>>()?,
         })
     }
@@ -183,18 +205,22 @@ impl Display for Coordinate {
 }
 
 impl FromStr for Coordinate {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut components = s.split(",").map(|component| {
-            component
-                .parse()
-                .map_err(|_| "Location component is not an integer")
-        });
+        let mut components = s
+            .split(",")
+            .map(|component| parse::token(component, "location component"));
+        let mut next = |name| {
+            components
+                .next()
+                .unwrap_or_else(|| Err(ParseError::after(s, format!("Missing {} component", name))))
+        };
+
         Ok(Self {
-            x: components.next().ok_or("Missing X component")??,
-            y: components.next().ok_or("Missing Y component")??,
-            z: components.next().ok_or("Missing Z component")??,
+            x: next("X")?,
+            y: next("Y")?,
+            z: next("Z")?,
         })
     }
 }
@@ -210,50 +236,3 @@ impl Sub for Coordinate {
         }
     }
 }
-
-fn main() -> Result<(), Box<dyn Error>> {
-    let problem = fs::read_to_string("input/day8.txt")?.parse::<Problem>()?;
-
-    println!("Part 1: {}", problem.part_1(1000)); // Attempts: 8, 57564
-    println!("Part 2: {}", problem.part_2()); // Attempts: 133296744
-
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE: &str = r#"
-162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689
-"#;
-
-    #[test]
-    fn test_sample_part_1() {
-        assert_eq!(40, SAMPLE.parse::<Problem>().unwrap().part_1(10));
-    }
-
-    #[test]
-    fn test_sample_part_2() {
-        assert_eq!(25272, SAMPLE.parse::<Problem>().unwrap().part_2());
-    }
-}
@@ -1,11 +1,13 @@
-use std::{collections::HashMap, error::Error, fs, str::FromStr};
+use aoc_common::parse::{self, ParseError};
+use aoc_common::puzzle::{Maybe, Solution};
+use std::{collections::HashMap, str::FromStr};
 
-struct Problem {
+pub struct Problem {
     floor_plan: FloorPlan,
 }
 
 impl FromStr for Problem {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
@@ -14,13 +16,21 @@ impl FromStr for Problem {
     }
 }
 
-impl Problem {
-    pub fn part_1(&self) -> u64 {
-        self.floor_plan.largest_rect_area()
+impl Solution for Problem {
+    type Answer1 = Maybe<u64>;
+    type Answer2 = Maybe<u64>;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(&self) -> Maybe<u64> {
+        self.floor_plan.largest_rect_area().into()
     }
 
-    pub fn part_2(&self) -> u64 {
-        self.floor_plan.largest_rect_area_constrained()
+    /// No answer when no rectangle fits inside the outline
+    fn part_2(&self) -> Maybe<u64> {
+        self.floor_plan.largest_rect_area_constrained().into()
     }
 }
 
@@ -122,28 +132,46 @@ struct FloorPlan {
 }
 
 impl FromStr for FloorPlan {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            red_tiles: s
-                .lines()
-                .map(|l| match l.split_once(',') {
-                    Some((x, y)) => Ok(Point {
-                        x: x.parse()
-                            .map_err(|_| format!("Invalid X coordinate: {}", x))?,
-                        y: y.parse()
-                            .map_err(|_| format!("Invalid Y coordinate: {}", y))?,
-                    }),
-                    None => Err("Unable to parse coordinate".to_string()),
+        let red_tiles = s
+            .lines()
+            .map(|l| {
+                let (x, y) = parse::split_once(l, ",")?;
+
+                Ok(Point {
+                    x: parse::token(x, "X coordinate")?,
+                    y: parse::token(y, "Y coordinate")?,
                 })
-                .collect::<Result<Vec<Point>, _>>()?,
-        })
+            })
+            .collect::<Result<Vec<Point>, _>>()?;
+
+        // The red tiles are the corners of an outline, which needs at least four of them with
+        // each one in the same row or column as the next
+        if red_tiles.len() < 4 {
+            return Err(ParseError::after(s, "Expected at least four red tiles"));
+        }
+        let next_tiles = red_tiles.iter().cycle().skip(1);
+        if let Some((line, _)) = s
+            .lines()
+            .zip(red_tiles.iter().zip(next_tiles))
+            .find(|(_, (tile, next))| tile.x != next.x && tile.y != next.y)
+        {
+            return Err(ParseError::at(
+                line,
+                "Expected the next red tile in the same row or column",
+            ));
+        }
+
+        Ok(Self { red_tiles })
     }
 }
 
 impl FloorPlan {
-    fn largest_rect_area(&self) -> u64 {
+    /// Area of the largest rectangle with red tiles at opposite corners, unless all red tiles are
+    /// the same
+    fn largest_rect_area(&self) -> Option<u64> {
         let mut areas = HashMap::new();
 
         for tile_a in self.red_tiles.iter() {
@@ -164,12 +192,10 @@ impl FloorPlan {
             }
         }
 
-        let mut sorted_areas: Vec<u64> = areas.into_values().collect();
-        sorted_areas.sort_by(|a, b| b.cmp(a));
-        sorted_areas[0]
+        areas.into_values().max()
     }
 
-    fn largest_rect_area_constrained(&self) -> u64 {
+    fn largest_rect_area_constrained(&self) -> Option<u64> {
         let mut boundaries: Vec<Line> = self
             .red_tiles
             .windows(2)
@@ -220,55 +246,30 @@ impl FloorPlan {
             }
         }
 
-        let mut sorted_areas: Vec<u64> = areas.into_values().flatten().collect();
-        sorted_areas.sort_by(|a, b| b.cmp(a));
-        sorted_areas[0]
+        areas.into_values().flatten().max()
     }
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let problem = fs::read_to_string("input/day9.txt")?.parse::<Problem>()?;
-
-    println!("Part 1: {}", problem.part_1()); // Attempts: 4737026542 (too low), 4737096935
-    println!("Part 2: {}", problem.part_2()); // Attempts: 1644094530
-
-    Ok(())
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const SAMPLE: &str = r#"
-7,1
-11,1
-11,7
-9,7
-9,5
-2,5
-2,3
-7,3
-"#;
-
     #[test]
     fn test_rectangle() {
         let rect = Rectangle::from_extrema(Point::new(0, 0), Point::new(4, 4));
 
         assert_eq!(25, rect.area());
-        assert_eq!(16, rect.inset_one().area());
+        assert_eq!(9, rect.inset_one().area());
 
         let rect = Rectangle::from_extrema(Point::new(4, 4), Point::new(0, 0));
 
-        assert_eq!(16, rect.inset_one().area());
-    }
-
-    #[test]
-    fn test_sample_part_1() {
-        assert_eq!(50, SAMPLE.parse::<Problem>().unwrap().part_1());
+        assert_eq!(9, rect.inset_one().area());
     }
 
     #[test]
-    fn test_sample_part_2() {
-        assert_eq!(24, SAMPLE.parse::<Problem>().unwrap().part_2());
+    fn test_invalid() {
+        assert!("7,1\n11,1\n11,7\n7,7".parse::<FloorPlan>().is_ok());
+        assert!("7,1\n11,1\n11,7".parse::<FloorPlan>().is_err());
+        assert!("7,1\n11,1\n11,7\n9,5".parse::<FloorPlan>().is_err());
     }
 }
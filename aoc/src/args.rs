@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Command line arguments split into positional arguments and `--name value` options
+#[derive(Debug, Default)]
+pub struct Args {
+    positional: Vec<String>,
+    options: HashMap<String, String>,
+}
+
+impl Args {
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut parsed = Self::default();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| format!("Missing value for option --{}", name))?;
+                    parsed.options.insert(name.to_string(), value.clone());
+                }
+                None => parsed.positional.push(arg.clone()),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// Parses the positional argument at the given index, `name` is only used in error messages
+    pub fn positional<T: FromStr>(&self, index: usize, name: &str) -> Result<T, String> {
+        self.optional_positional(index, name)?
+            .ok_or_else(|| format!("Missing argument <{}>", name))
+    }
+
+    /// Like `positional`, but the argument may be left out
+    pub fn optional_positional<T: FromStr>(
+        &self,
+        index: usize,
+        name: &str,
+    ) -> Result<Option<T>, String> {
+        self.positional
+            .get(index)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| format!("Invalid {}: {}", name, value))
+            })
+            .transpose()
+    }
+
+    /// Fails on the first option, by name, that is not one of the known ones
+    pub fn check_options(&self, known: &[&str]) -> Result<(), String> {
+        match self
+            .options
+            .keys()
+            .filter(|name| !known.contains(&name.as_str()))
+            .min()
+        {
+            Some(name) => Err(format!("Unknown option --{}", name)),
+            None => Ok(()),
+        }
+    }
+
+    /// Parses the option with the given name, if it was passed
+    pub fn option<T: FromStr<Err: ToString>>(&self, name: &str) -> Result<Option<T>, String> {
+        self.options
+            .get(name)
+            .map(|value| value.parse().map_err(|e: T::Err| e.to_string()))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_whitespace().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let parsed = Args::parse(&args("run 2024 16 --part 1 --input -")).unwrap();
+
+        assert_eq!(Ok("run".to_string()), parsed.positional(0, "command"));
+        assert_eq!(Ok(2024), parsed.positional::<u16>(1, "year"));
+        assert_eq!(Ok(16), parsed.positional::<u8>(2, "day"));
+        assert_eq!(Ok(Some(1)), parsed.option::<u8>("part"));
+        assert_eq!(Ok(Some("-".to_string())), parsed.option("input"));
+        assert_eq!(Ok(None), parsed.option::<String>("format"));
+        assert_eq!(Ok(Some(16)), parsed.optional_positional::<u8>(2, "day"));
+        assert_eq!(Ok(None), parsed.optional_positional::<u8>(3, "day"));
+        assert!(parsed.positional::<u8>(3, "day").is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Args::parse(&args("run 2024 16 --part")).is_err());
+
+        let parsed = Args::parse(&args("run twenty --part x")).unwrap();
+        assert!(parsed.positional::<u16>(1, "year").is_err());
+        assert!(parsed.positional::<u8>(2, "day").is_err());
+        assert!(parsed.option::<u8>("part").is_err());
+    }
+
+    #[test]
+    fn test_check_options() {
+        let parsed = Args::parse(&args("run --yaer 2024 --part 1")).unwrap();
+
+        assert_eq!(
+            Err("Unknown option --yaer".to_string()),
+            parsed.check_options(&["part", "input"])
+        );
+        assert_eq!(Ok(()), parsed.check_options(&["part", "yaer"]));
+        assert!(
+            Args::parse(&args("readme --part 1"))
+                .unwrap()
+                .check_options(&[])
+                .is_err()
+        );
+    }
+}
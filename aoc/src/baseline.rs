@@ -0,0 +1,260 @@
+use crate::bench::{DayTimings, Stage, format_duration};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::Duration;
+use std::{env, fs};
+
+/// Directory containing one baseline file per machine
+const BASELINE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/baselines");
+
+/// Name of this machine, so timings of different machines are not compared with each other.
+/// Can be overridden with the `AOC_MACHINE` environment variable.
+pub fn machine() -> String {
+    env::var("AOC_MACHINE")
+        .or_else(|_| env::var("HOSTNAME"))
+        .or_else(|_| fs::read_to_string("/etc/hostname"))
+        .map(|name| name.trim().to_string())
+        .ok()
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "default".to_string())
+}
+
+/// Default location of the baseline of the given machine
+pub fn baseline_path(machine: &str) -> String {
+    format!("{}/{}.txt", BASELINE_DIR, machine)
+}
+
+/// Median time of every benchmarked stage, keyed by year, day and stage
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Baseline {
+    medians: BTreeMap<(u16, u8, Stage), Duration>,
+}
+
+impl Baseline {
+    /// Loads the baseline from the given file, a missing file means no baseline was saved yet
+    pub fn load(path: &str) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents.parse().map_err(|e| format!("{}: {}", path, e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Failed to read {}: {}", path, e)),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        if let Some(dir) = std::path::Path::new(path).parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        }
+
+        fs::write(path, self.to_string()).map_err(|e| format!("Failed to write {}: {}", path, e))
+    }
+
+    pub fn median(&self, year: u16, day: u8, stage: Stage) -> Option<Duration> {
+        self.medians.get(&(year, day, stage)).copied()
+    }
+
+    /// Stores the medians of the given results, replacing earlier medians of the same stages
+    pub fn record(&mut self, results: &[DayTimings]) {
+        for result in results {
+            for (stage, timings) in result.stages() {
+                self.medians
+                    .insert((result.year, result.day, stage), timings.median);
+            }
+        }
+    }
+}
+
+impl FromStr for Baseline {
+    type Err = String;
+
+    /// One stage per line formatted as `<year> <day> <stage> <median_ns>`, empty lines and lines
+    /// starting with `#` are ignored
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut baseline = Self::default();
+
+        for (i, line) in s.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || format!("Invalid baseline on line {}: {}", i + 1, line);
+            let [year, day, stage, median] = line
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .try_into()
+                .map_err(|_| invalid())?;
+
+            baseline.medians.insert(
+                (
+                    year.parse().map_err(|_| invalid())?,
+                    day.parse().map_err(|_| invalid())?,
+                    stage.parse().map_err(|_| invalid())?,
+                ),
+                Duration::from_nanos(median.parse().map_err(|_| invalid())?),
+            );
+        }
+
+        Ok(baseline)
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# <year> <day> <stage> <median_ns>")?;
+
+        for ((year, day, stage), median) in &self.medians {
+            writeln!(f, "{} {} {} {}", year, day, stage, median.as_nanos())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Change in median time of one stage compared to the baseline
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub stage: Stage,
+    pub baseline: Option<Duration>,
+    pub median: Duration,
+}
+
+impl Comparison {
+    /// Relative change in percent, positive when the stage got slower
+    pub fn change(&self) -> Option<f64> {
+        self.baseline.map(|baseline| {
+            (self.median.as_secs_f64() / baseline.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0)
+                * 100.0
+        })
+    }
+
+    /// Whether the stage slowed down by more than the given percentage
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+pub fn compare(baseline: &Baseline, results: &[DayTimings]) -> Vec<Comparison> {
+    results
+        .iter()
+        .flat_map(|result| {
+            result.stages().map(|(stage, timings)| Comparison {
+                year: result.year,
+                day: result.day,
+                stage,
+                baseline: baseline.median(result.year, result.day, stage),
+                median: timings.median,
+            })
+        })
+        .collect()
+}
+
+/// Prints a row per compared stage and returns the number of regressions
+pub fn report(comparisons: &[Comparison], threshold: f64) -> usize {
+    let mut regressions = 0;
+
+    for comparison in comparisons {
+        let status = match comparison.change() {
+            None => "no baseline".to_string(),
+            Some(change) if comparison.is_regression(threshold) => {
+                regressions += 1;
+                format!("{:+.1}% REGRESSION", change)
+            }
+            Some(change) => format!("{:+.1}%", change),
+        };
+
+        println!(
+            "{} day {:>2} {:<8} {:>10} -> {:>10}  {}",
+            comparison.year,
+            comparison.day,
+            comparison.stage.to_string(),
+            comparison
+                .baseline
+                .map(format_duration)
+                .unwrap_or_else(|| "-".to_string()),
+            format_duration(comparison.median),
+            status
+        );
+    }
+
+    println!(
+        "\n{} regressions beyond {}% in {} stages",
+        regressions,
+        threshold,
+        comparisons.len()
+    );
+
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::Timings;
+    use aoc_common::puzzle::Part;
+
+    fn day_timings(year: u16, day: u8, parse: u64, part_1: u64) -> DayTimings {
+        let timings = |millis| Timings {
+            min: Duration::from_millis(millis),
+            median: Duration::from_millis(millis),
+            max: Duration::from_millis(millis),
+        };
+
+        DayTimings {
+            year,
+            day,
+            parse: timings(parse),
+            parts: [Some(timings(part_1)), None],
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let baseline =
+            "# <year> <day> <stage> <median_ns>\n2024 6 part-2 1500\n\n2024 6 parse 20\n"
+                .parse::<Baseline>()
+                .unwrap();
+
+        assert_eq!(
+            Some(Duration::from_nanos(1500)),
+            baseline.median(2024, 6, Stage::Part(Part::Two))
+        );
+        assert_eq!(
+            Some(Duration::from_nanos(20)),
+            baseline.median(2024, 6, Stage::Parse)
+        );
+        assert_eq!(None, baseline.median(2024, 6, Stage::Part(Part::One)));
+        assert_eq!(baseline, baseline.to_string().parse().unwrap());
+
+        assert!("2024 6 part-3 1500".parse::<Baseline>().is_err());
+        assert!("2024 6 parse".parse::<Baseline>().is_err());
+        assert!("2024 6 parse 1.5".parse::<Baseline>().is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut baseline = Baseline::default();
+        baseline.record(&[day_timings(2024, 6, 10, 100)]);
+        baseline.record(&[day_timings(2024, 6, 20, 1000)]);
+        assert_eq!(
+            Some(Duration::from_millis(1000)),
+            baseline.median(2024, 6, Stage::Part(Part::One))
+        );
+
+        let comparisons = compare(
+            &baseline,
+            &[day_timings(2024, 6, 23, 500), day_timings(2024, 7, 1, 1)],
+        );
+
+        assert_eq!(4, comparisons.len());
+        assert_eq!(Some(15.0), comparisons[0].change().map(f64::round));
+        assert!(comparisons[0].is_regression(10.0));
+        assert!(!comparisons[0].is_regression(20.0));
+        assert_eq!(Some(-50.0), comparisons[1].change().map(f64::round));
+        assert!(!comparisons[1].is_regression(10.0));
+        assert_eq!(None, comparisons[2].change());
+        assert!(!comparisons[2].is_regression(10.0));
+    }
+}
@@ -0,0 +1,303 @@
+use crate::config::Config;
+use aoc_common::puzzle::{Day, Part, Year};
+use std::fmt::{Display, Formatter, Write};
+use std::fs;
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Part of a day's solution that is timed separately
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Self::Parse),
+            "part-1" => Ok(Self::Part(Part::One)),
+            "part-2" => Ok(Self::Part(Part::Two)),
+            _ => Err(format!("Unknown stage: {}", s)),
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part(part) => write!(f, "part-{}", part),
+        }
+    }
+}
+
+/// Limits how often each stage is repeated: at most `runs` times, but no more runs are started
+/// once `max_time` has been spent on the stage. Every stage runs at least once.
+#[derive(Debug, Copy, Clone)]
+pub struct Limits {
+    pub runs: usize,
+    pub max_time: Duration,
+}
+
+/// Wall time statistics over all runs of a single stage
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timings {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Timings of the stages of one day, parts that are not solved yet have no timings
+#[derive(Debug, Clone)]
+pub struct DayTimings {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Timings,
+    pub parts: [Option<Timings>; 2],
+}
+
+impl DayTimings {
+    /// Timings of each stage along with its name, skipping unsolved parts
+    pub fn stages(&self) -> impl Iterator<Item = (Stage, Timings)> {
+        [(Stage::Parse, Some(self.parse))]
+            .into_iter()
+            .chain(
+                Part::ALL
+                    .into_iter()
+                    .zip(self.parts)
+                    .map(|(part, timings)| (Stage::Part(part), timings)),
+            )
+            .filter_map(|(stage, timings)| Some((stage, timings?)))
+    }
+}
+
+fn measure<T>(limits: Limits, mut f: impl FnMut() -> T) -> Timings {
+    let started = Instant::now();
+    let mut samples = vec![];
+
+    while samples.is_empty() || (samples.len() < limits.runs && started.elapsed() < limits.max_time)
+    {
+        let start = Instant::now();
+        black_box(f());
+        samples.push(start.elapsed());
+    }
+
+    Timings::from_samples(samples)
+}
+
+pub fn bench_day(year: u16, day: &Day, input: &str, limits: Limits) -> Result<DayTimings, String> {
+    let parse = measure(limits, || (day.parse)(input));
+    let solution = (day.parse)(input)?;
+
+    Ok(DayTimings {
+        year,
+        day: day.day,
+        parse,
+        parts: Part::ALL.map(|part| {
+            day.is_solved(part)
+                .then(|| measure(limits, || solution.solve(part)))
+        }),
+    })
+}
+
+/// Benchmarks the given days of each year, days without an input file are skipped
+pub fn bench(
+    config: &Config,
+    years: &[&Year],
+    day: Option<u8>,
+    limits: Limits,
+) -> Result<Vec<DayTimings>, String> {
+    let mut results = vec![];
+
+    for year in years {
+        for solution in year
+            .days
+            .iter()
+            .filter(|d| day.is_none_or(|day| d.day == day))
+        {
+            let path = config.input_path(year, solution.day);
+            let Ok(input) = fs::read_to_string(&path) else {
+                eprintln!(
+                    "{} day {:>2}: skipped, missing input {}",
+                    year.year, solution.day, path
+                );
+                continue;
+            };
+
+            results.push(bench_day(year.year, solution, &input, limits)?);
+        }
+    }
+
+    Ok(results)
+}
+
+/// Formats a duration with a unit that keeps the number readable, e.g. `12.3µs`
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+
+    match nanos {
+        n if n < 1e3 => format!("{:.0}ns", n),
+        n if n < 1e6 => format!("{:.1}µs", n / 1e3),
+        n if n < 1e9 => format!("{:.1}ms", n / 1e6),
+        n => format!("{:.2}s", n / 1e9),
+    }
+}
+
+/// One table per year, with a row per stage of each day
+pub fn table(results: &[DayTimings]) -> String {
+    let mut table = String::new();
+
+    for (i, result) in results.iter().enumerate() {
+        if i == 0 || results[i - 1].year != result.year {
+            if i > 0 {
+                table.push('\n');
+            }
+            writeln!(
+                table,
+                "{:<15} {:>10} {:>10} {:>10}",
+                result.year, "min", "median", "max"
+            )
+            .unwrap();
+        }
+
+        for (stage, timings) in result.stages() {
+            writeln!(
+                table,
+                "day {:>2} {:<8} {:>10} {:>10} {:>10}",
+                result.day,
+                stage.to_string(),
+                format_duration(timings.min),
+                format_duration(timings.median),
+                format_duration(timings.max)
+            )
+            .unwrap();
+        }
+    }
+
+    table
+}
+
+pub fn json(results: &[DayTimings]) -> String {
+    let timings_json = |timings: Option<Timings>| match timings {
+        Some(t) => format!(
+            r#"{{"min_ns": {}, "median_ns": {}, "max_ns": {}}}"#,
+            t.min.as_nanos(),
+            t.median.as_nanos(),
+            t.max.as_nanos()
+        ),
+        None => "null".to_string(),
+    };
+
+    let days = results
+        .iter()
+        .map(|result| {
+            format!(
+                r#"  {{"year": {}, "day": {}, "parse": {}, "part_1": {}, "part_2": {}}}"#,
+                result.year,
+                result.day,
+                timings_json(Some(result.parse)),
+                timings_json(result.parts[0]),
+                timings_json(result.parts[1])
+            )
+        })
+        .collect::<Vec<_>>();
+
+    format!("[\n{}\n]", days.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    fn sample() -> Vec<DayTimings> {
+        let timings = Timings {
+            min: ms(1),
+            median: ms(2),
+            max: ms(30),
+        };
+
+        vec![DayTimings {
+            year: 2024,
+            day: 16,
+            parse: timings,
+            parts: [Some(timings), None],
+        }]
+    }
+
+    #[test]
+    fn test_timings() {
+        assert_eq!(
+            Timings {
+                min: ms(1),
+                median: ms(3),
+                max: ms(9)
+            },
+            Timings::from_samples(vec![ms(9), ms(3), ms(1), ms(4), ms(2)])
+        );
+
+        let limits = Limits {
+            runs: 3,
+            max_time: Duration::from_secs(60),
+        };
+        let mut runs = 0;
+        measure(limits, || runs += 1);
+        assert_eq!(3, runs);
+
+        let limits = Limits {
+            runs: 100,
+            max_time: Duration::ZERO,
+        };
+        let mut runs = 0;
+        measure(limits, || runs += 1);
+        assert_eq!(1, runs);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!("512ns", format_duration(Duration::from_nanos(512)));
+        assert_eq!("12.3µs", format_duration(Duration::from_nanos(12_345)));
+        assert_eq!("2.0ms", format_duration(ms(2)));
+        assert_eq!("1.50s", format_duration(ms(1500)));
+    }
+
+    #[test]
+    fn test_table() {
+        assert_eq!(
+            "2024                   min     median        max\n\
+             day 16 parse         1.0ms      2.0ms     30.0ms\n\
+             day 16 part-1        1.0ms      2.0ms     30.0ms\n",
+            table(&sample())
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            "[\n  {\"year\": 2024, \"day\": 16, \
+             \"parse\": {\"min_ns\": 1000000, \"median_ns\": 2000000, \"max_ns\": 30000000}, \
+             \"part_1\": {\"min_ns\": 1000000, \"median_ns\": 2000000, \"max_ns\": 30000000}, \
+             \"part_2\": null}\n]",
+            json(&sample())
+        );
+    }
+}
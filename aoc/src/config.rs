@@ -0,0 +1,142 @@
+use aoc_common::puzzle::Year;
+use std::{env, fs};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings for talking to the puzzle website, read from the config file and the environment
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged in browser
+    pub session: Option<String>,
+    /// Endpoint the puzzle paths like `/2024/day/6/input` are appended to
+    pub base_url: String,
+    /// Directory with an `<year>/day<N>.txt` input per day, instead of each year's input directory
+    pub cache_dir: Option<String>,
+}
+
+impl Config {
+    /// Loads the config file (`$AOC_CONFIG`, or `~/.config/aoc/config` by default), of which every
+    /// setting can be overridden by the environment variables `AOC_SESSION`, `AOC_BASE_URL` and
+    /// `AOC_CACHE_DIR`
+    pub fn load() -> Result<Self, String> {
+        let path = env::var("AOC_CONFIG").ok().or_else(|| {
+            env::var("XDG_CONFIG_HOME")
+                .or_else(|_| env::var("HOME").map(|home| format!("{}/.config", home)))
+                .ok()
+                .map(|dir| format!("{}/aoc/config", dir))
+        });
+        let contents = match path {
+            Some(path) => match fs::read_to_string(&path) {
+                Ok(contents) => contents,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+                Err(e) => return Err(format!("Failed to read {}: {}", path, e)),
+            },
+            None => String::new(),
+        };
+
+        Self::parse(&contents, |name| env::var(name).ok())
+    }
+
+    /// Parses `<key> = <value>` lines, empty lines and lines starting with `#` are ignored.
+    /// Settings found by `env` take precedence over the file.
+    pub fn parse(contents: &str, env: impl Fn(&str) -> Option<String>) -> Result<Self, String> {
+        let mut config = Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            cache_dir: None,
+        };
+
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .map(|(key, value)| (key.trim(), value.trim().to_string()))
+                .ok_or_else(|| format!("Invalid config on line {}: {}", i + 1, line))?;
+
+            match key {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                "cache_dir" => config.cache_dir = Some(value),
+                _ => return Err(format!("Unknown config key on line {}: {}", i + 1, key)),
+            }
+        }
+
+        if let Some(session) = env("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Some(base_url) = env("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+        if let Some(cache_dir) = env("AOC_CACHE_DIR") {
+            config.cache_dir = Some(cache_dir);
+        }
+
+        config.base_url = config.base_url.trim_end_matches('/').to_string();
+
+        Ok(config)
+    }
+
+    pub fn session(&self) -> Result<&str, String> {
+        self.session.as_deref().ok_or_else(|| {
+            "No session token, set AOC_SESSION or add `session = <token>` to the config file"
+                .to_string()
+        })
+    }
+
+    /// Location of the input of the given day, in the cache directory if one is configured
+    pub fn input_path(&self, year: &Year, day: u8) -> String {
+        match &self.cache_dir {
+            Some(dir) => format!("{}/{}/day{}.txt", dir, year.year, day),
+            None => year.input_path(day),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let contents = "# puzzle website\nsession = abc123\n\ncache_dir=/tmp/aoc\n";
+
+        let config = Config::parse(contents, |_| None).unwrap();
+        assert_eq!(Ok("abc123"), config.session());
+        assert_eq!("https://adventofcode.com", config.base_url);
+        assert_eq!(Some("/tmp/aoc".to_string()), config.cache_dir);
+
+        let config = Config::parse(contents, |name| match name {
+            "AOC_SESSION" => Some("def456".to_string()),
+            "AOC_BASE_URL" => Some("http://127.0.0.1:8080/".to_string()),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(Ok("def456"), config.session());
+        assert_eq!("http://127.0.0.1:8080", config.base_url);
+
+        assert!(Config::parse("", |_| None).unwrap().session().is_err());
+        assert!(Config::parse("session abc123", |_| None).is_err());
+        assert!(Config::parse("token = abc123", |_| None).is_err());
+    }
+
+    #[test]
+    fn test_input_path() {
+        const YEAR: Year = Year {
+            year: 2024,
+            input_dir: "2024/input",
+            answers_path: "2024/answers.txt",
+            learnings_path: "2024/learnings.txt",
+            days: &[],
+        };
+
+        let config = Config::parse("", |_| None).unwrap();
+        assert_eq!("2024/input/day6.txt", config.input_path(&YEAR, 6));
+
+        let config = Config::parse("cache_dir = cache", |_| None).unwrap();
+        assert_eq!("cache/2024/day6.txt", config.input_path(&YEAR, 6));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::config::Config;
+use crate::http;
+use aoc_common::puzzle::Year;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Fetched {
+    /// The input was cached already, so it wasn't requested again
+    Cached(String),
+    Downloaded(String),
+}
+
+/// Makes sure the input of the given day is cached, downloading it only if it isn't yet
+pub fn fetch_input(config: &Config, year: &Year, day: u8) -> Result<Fetched, String> {
+    let path = config.input_path(year, day);
+    if Path::new(&path).exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let url = format!("{}/{}/day/{}/input", config.base_url, year.year, day);
+    let response = http::request(&url, config.session()?, None)?;
+    if response.status != 200 {
+        return Err(format!(
+            "Failed to fetch {}: status {}, {}",
+            url,
+            response.status,
+            response.body.lines().next().unwrap_or_default()
+        ));
+    }
+
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+
+    // Written under a temporary name first, so an interrupted write never ends up in the cache
+    let partial = format!("{}.partial", path);
+    fs::write(&partial, response.body)
+        .and_then(|_| fs::rename(&partial, &path))
+        .map_err(|e| format!("Failed to write {}: {}", path, e))?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+/// Fetches the inputs of the given day, or of all registered days of the year
+pub fn fetch(config: &Config, year: &Year, day: Option<u8>) -> Result<(), String> {
+    for solution in year
+        .days
+        .iter()
+        .filter(|d| day.is_none_or(|day| d.day == day))
+    {
+        match fetch_input(config, year, solution.day)? {
+            Fetched::Cached(path) => println!(
+                "{} day {:>2}: already cached in {}",
+                year.year, solution.day, path
+            ),
+            Fetched::Downloaded(path) => {
+                println!("{} day {:>2}: saved to {}", year.year, solution.day, path)
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::tests::{response, serve};
+    use std::env;
+
+    #[test]
+    fn test_fetch_input() {
+        let cache_dir = env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let (url, server) = serve(vec![
+            response(200, "3   4\n4   3\n"),
+            response(
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+        ]);
+        let config = Config::parse("session = abc", |name| match name {
+            "AOC_BASE_URL" => Some(url.clone()),
+            "AOC_CACHE_DIR" => Some(cache_dir.display().to_string()),
+            _ => None,
+        })
+        .unwrap();
+        const YEAR: Year = Year {
+            year: 2024,
+            input_dir: "input",
+            answers_path: "answers.txt",
+            learnings_path: "learnings.txt",
+            days: &[],
+        };
+
+        let path = format!("{}/2024/day1.txt", cache_dir.display());
+        assert_eq!(
+            Ok(Fetched::Downloaded(path.clone())),
+            fetch_input(&config, &YEAR, 1)
+        );
+        assert_eq!("3   4\n4   3\n", fs::read_to_string(&path).unwrap());
+
+        // Served from the cache, so the next request the server receives is the one for day 2
+        assert_eq!(Ok(Fetched::Cached(path)), fetch_input(&config, &YEAR, 1));
+        assert!(fetch_input(&config, &YEAR, 2).is_err());
+        assert!(!Path::new(&config.input_path(&YEAR, 2)).exists());
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2024/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[1].starts_with("GET /2024/day/2/input HTTP/1.1\r\n"));
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+}
@@ -0,0 +1,256 @@
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+
+/// Identifies the runner to the puzzle website, as its maintainers ask of automated tools
+const USER_AGENT: &str = "github.com/fred-jan/advent-of-code";
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends a request with the given session cookie. A form body turns it into a POST request.
+///
+/// Plain `http://` URLs are requested over a TCP connection, which is all a local test server
+/// needs. The standard library has no TLS, so `https://` URLs are requested with `curl`.
+pub fn request(url: &str, session: &str, form: Option<&str>) -> Result<Response, String> {
+    match url.strip_prefix("http://") {
+        Some(rest) => request_plain(rest, session, form),
+        None if url.starts_with("https://") => request_curl(url, session, form),
+        None => Err(format!("Unsupported URL: {}", url)),
+    }
+}
+
+fn request_plain(url: &str, session: &str, form: Option<&str>) -> Result<Response, String> {
+    let (host, path) = match url.find('/') {
+        Some(i) => url.split_at(i),
+        None => (url, "/"),
+    };
+    let address = match host.contains(':') {
+        true => host.to_string(),
+        false => format!("{}:80", host),
+    };
+
+    let mut request = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: {}\r\nCookie: session={}\r\nConnection: close\r\n",
+        if form.is_some() { "POST" } else { "GET" },
+        path,
+        host,
+        USER_AGENT,
+        session
+    );
+    if let Some(form) = form {
+        request.push_str(&format!(
+            "Content-Type: application/x-www-form-urlencoded\r\nContent-Length: {}\r\n\r\n{}",
+            form.len(),
+            form
+        ));
+    } else {
+        request.push_str("\r\n");
+    }
+
+    let mut stream = TcpStream::connect(&address)
+        .map_err(|e| format!("Failed to connect to {}: {}", host, e))?;
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|e| format!("Failed to send request to {}: {}", host, e))?;
+
+    let mut response = Vec::new();
+    stream
+        .read_to_end(&mut response)
+        .map_err(|e| format!("Failed to read response from {}: {}", host, e))?;
+
+    parse_response(&String::from_utf8_lossy(&response))
+}
+
+/// Parses a raw HTTP/1.1 response, decoding chunked bodies
+fn parse_response(response: &str) -> Result<Response, String> {
+    let invalid = || "Invalid HTTP response".to_string();
+    let (head, body) = response.split_once("\r\n\r\n").ok_or_else(invalid)?;
+    let mut lines = head.lines();
+
+    let status = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|status| status.parse().ok())
+        .ok_or_else(invalid)?;
+    let chunked = lines.any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.eq_ignore_ascii_case("transfer-encoding") && value.trim() == "chunked"
+        })
+    });
+
+    let body = match chunked {
+        true => decode_chunked(body).ok_or_else(invalid)?,
+        false => body.to_string(),
+    };
+
+    Ok(Response { status, body })
+}
+
+fn decode_chunked(mut body: &str) -> Option<String> {
+    let mut decoded = String::new();
+
+    loop {
+        let (size, rest) = body.split_once("\r\n")?;
+        let size = usize::from_str_radix(size.split(';').next()?.trim(), 16).ok()?;
+        if size == 0 {
+            return Some(decoded);
+        }
+
+        decoded.push_str(rest.get(..size)?);
+        body = rest.get(size..)?.strip_prefix("\r\n")?;
+    }
+}
+
+fn request_curl(url: &str, session: &str, form: Option<&str>) -> Result<Response, String> {
+    let mut command = Command::new("curl");
+    command
+        .args(["--silent", "--show-error", "--user-agent", USER_AGENT])
+        // Headers are read from stdin, so the session doesn't show up in the process list
+        .args(["--header", "@-", "--write-out", "\n%{http_code}"]);
+    if let Some(form) = form {
+        command.args(["--data", form]);
+    }
+
+    let mut child = command
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to run curl: {}", e))?;
+    child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(format!("Cookie: session={}\n", session).as_bytes())
+        .map_err(|e| format!("Failed to pass headers to curl: {}", e))?;
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("Failed to run curl: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "curl failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let output = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = output
+        .rsplit_once('\n')
+        .ok_or_else(|| "Invalid curl output".to_string())?;
+
+    Ok(Response {
+        status: status
+            .parse()
+            .map_err(|_| format!("Invalid status from curl: {}", status))?,
+        body: body.to_string(),
+    })
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Serves the given responses to consecutive connections on a local port. Returns the base
+    /// URL and a handle that yields the received requests once all responses have been served.
+    pub fn serve(responses: Vec<String>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|response| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let request = read_request(&mut stream);
+                    stream.write_all(response.as_bytes()).unwrap();
+                    request
+                })
+                .collect()
+        });
+
+        (url, handle)
+    }
+
+    fn read_request(stream: &mut TcpStream) -> String {
+        let mut request = Vec::new();
+        let mut buffer = [0; 1024];
+
+        loop {
+            let read = stream.read(&mut buffer).unwrap();
+            request.extend_from_slice(&buffer[..read]);
+
+            let text = String::from_utf8_lossy(&request);
+            if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                let length = head
+                    .lines()
+                    .find_map(|line| line.strip_prefix("Content-Length: "))
+                    .map_or(0, |length| length.parse().unwrap());
+                if body.len() >= length {
+                    return text.to_string();
+                }
+            }
+        }
+    }
+
+    pub fn response(status: u16, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {} Status\r\nContent-Length: {}\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        )
+    }
+
+    #[test]
+    fn test_request() {
+        let (url, server) = serve(vec![response(200, "1 2 3\n"), response(404, "Not found")]);
+
+        assert_eq!(
+            Ok(Response {
+                status: 200,
+                body: "1 2 3\n".to_string()
+            }),
+            request(&format!("{}/2024/day/2/input", url), "abc", None)
+        );
+        assert_eq!(
+            Ok(404),
+            request(
+                &format!("{}/2024/day/2/answer", url),
+                "abc",
+                Some("level=1&answer=2")
+            )
+            .map(|response| response.status)
+        );
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2024/day/2/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc\r\n"));
+        assert!(requests[1].starts_with("POST /2024/day/2/answer HTTP/1.1\r\n"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=1&answer=2"));
+
+        assert!(request("ftp://localhost/input", "abc", None).is_err());
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            Ok(Response {
+                status: 200,
+                body: "Wikipedia in\r\n\r\nchunks.".to_string()
+            }),
+            parse_response(
+                "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n\
+                 4\r\nWiki\r\n5\r\npedia\r\nE\r\n in\r\n\r\nchunks.\r\n0\r\n\r\n"
+            )
+        );
+        assert!(parse_response("garbage").is_err());
+    }
+}
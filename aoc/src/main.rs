@@ -0,0 +1,394 @@
+mod args;
+mod baseline;
+mod bench;
+mod config;
+mod fetch;
+mod http;
+mod readme;
+mod render;
+mod scaffold;
+mod submit;
+mod verify;
+
+use aoc_common::answers::Answers;
+use aoc_common::puzzle::{Day, Part, Parts, Year};
+use args::Args;
+use baseline::Baseline;
+use config::Config;
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+use std::{env, fs, io, thread};
+
+const USAGE: &str = "Usage:
+  aoc run <year> <day> [--part <1|2>] [--input <path|->]
+  aoc render <year> <day> [--input <path|->] [--format <ansi|ppm|png|gif>] [--scale <n>]
+             [--every <n>] [--from <n>] [--to <n>] [--delay <ms>] [--output <path>]
+  aoc new <year> <day>
+  aoc fetch <year> [<day>]
+  aoc submit <year> <day> <part> [<answer>]
+  aoc verify [<year> [<day>]]
+  aoc readme
+  aoc bench [<year> [<day>]] [--runs <n>] [--max-time <seconds>] [--format <table|json>]
+  aoc baseline [<year> [<day>]] [--runs <n>] [--max-time <seconds>] [--machine <name>]
+  aoc compare [<year> [<day>]] [--runs <n>] [--max-time <seconds>] [--machine <name>]
+              [--threshold <percent>]
+
+Without --part both parts are solved. The input defaults to the input file of the
+given day in the year's input directory, pass - to read it from stdin instead.
+
+render shows the simulation of days that have one, like the robots of 2024 day 14,
+animated in the terminal, written as numbered images to the --output directory
+(frames by default) or as an animated GIF to the --output file (frames.gif by
+default). Every cell becomes a square of --scale pixels (1 by default), only every
+--every-th frame from frame --from up to and including frame --to is shown (all by
+default) for --delay milliseconds (50 by default).
+
+new creates the solution file of a day from a template, registers it in the year's
+crate and adds an empty fixture for its sample. Existing days are never overwritten.
+
+fetch downloads the inputs of the given day, or of all days of the given year, unless
+they were downloaded before. It needs the session cookie of a logged in browser,
+passed as AOC_SESSION or configured in the config file (AOC_CONFIG, by default
+~/.config/aoc/config) as `session = <token>`. The file can also set `base_url` and
+`cache_dir` (or AOC_BASE_URL and AOC_CACHE_DIR), in which case inputs are stored as
+<cache_dir>/<year>/day<N>.txt instead of in each year's input directory.
+
+submit posts the given answer, or the answer the solution gives for the day's input,
+and records the verdict in the year's answers file. Answers that are known to be
+wrong from earlier attempts are not submitted again, and when answering too soon
+the cooldown is waited out before retrying.
+
+verify solves all registered days (or only those of the given year or day) and
+compares the answers with the year's answers file.
+
+readme regenerates the stars and learnings tables in README.md from the accepted
+answers in the answers files and the learnings file of each year.
+
+bench times parsing and both parts of the selected days. Every stage is repeated
+--runs times (10 by default), but no more runs are started once a stage took
+--max-time seconds (5 by default). The minimum, median and maximum wall times are
+printed as a table per year, or as JSON.
+
+baseline benchmarks the selected days and stores their median times in the
+baseline of this machine, named after the hostname unless --machine or the
+AOC_MACHINE environment variable is given. compare benchmarks the selected days
+again and fails if any stage got slower than its baseline by more than
+--threshold percent (10 by default).";
+
+const YEARS: [Year; 3] = [aoc23::YEAR, aoc24::YEAR, advent_of_code_2025::YEAR];
+
+fn find_year(year: u16) -> Result<&'static Year, String> {
+    YEARS
+        .iter()
+        .find(|y| y.year == year)
+        .ok_or_else(|| format!("No solutions for year {}", year))
+}
+
+/// Reads the input from the given path, or from stdin if the path is `-`
+fn read_input(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| format!("Failed to read input from stdin: {}", e))?;
+
+        return Ok(input);
+    }
+
+    fs::read_to_string(path).map_err(|e| format!("Failed to read input {}: {}", path, e))
+}
+
+/// Parses the input of the day given by the <year> and <day> arguments, read from --input or
+/// the day's input file
+fn parse_input(args: &Args) -> Result<(&'static Day, Box<dyn Parts>), String> {
+    let year = find_year(args.positional(1, "year")?)?;
+    let day: u8 = args.positional(2, "day")?;
+    let solution = year
+        .day(day)
+        .ok_or_else(|| format!("No solution for {} day {}", year.year, day))?;
+
+    let input = read_input(&match args.option::<String>("input")? {
+        Some(path) => path,
+        None => Config::load()?.input_path(year, day),
+    })?;
+
+    Ok((solution, (solution.parse)(&input)?))
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    let parts = match args.option::<Part>("part")? {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let (day, solution) = parse_input(args)?;
+
+    for part in parts {
+        match solution.solve(part) {
+            Some(answer) => println!("Part {}: {}", part, answer),
+            None if day.is_solved(part) => println!("Part {}: no answer for this input", part),
+            None => println!("Part {}: not solved yet", part),
+        }
+    }
+
+    Ok(())
+}
+
+fn render_frames(args: &Args) -> Result<(), String> {
+    let format = args.option("format")?.unwrap_or(render::Format::Ansi);
+    let output = match format {
+        render::Format::Gif => "frames.gif",
+        _ => "frames",
+    };
+    let options = render::Options {
+        format,
+        scale: args.option("scale")?.unwrap_or(1),
+        every: args.option("every")?.unwrap_or(1),
+        from: args.option("from")?.unwrap_or(0),
+        to: args.option("to")?,
+        delay: Duration::from_millis(args.option("delay")?.unwrap_or(50)),
+        output: PathBuf::from(
+            args.option::<String>("output")?
+                .unwrap_or(output.to_string()),
+        ),
+    };
+
+    match render::render(&*parse_input(args)?.1, &options)? {
+        0 => Err("Nothing to render for this day".to_string()),
+        _ if options.format == render::Format::Ansi => Ok(()),
+        frames => {
+            println!("Wrote {} frames to {}", frames, options.output.display());
+            Ok(())
+        }
+    }
+}
+
+/// Years selected by the optional <year> argument, all years if it was left out
+fn selected_years(args: &Args) -> Result<Vec<&'static Year>, String> {
+    match args.optional_positional(1, "year")? {
+        Some(year) => Ok(vec![find_year(year)?]),
+        None => Ok(YEARS.iter().collect()),
+    }
+}
+
+fn new(args: &Args) -> Result<(), String> {
+    let year = find_year(args.positional(1, "year")?)?;
+    let day: u8 = args.positional(2, "day")?;
+
+    for path in scaffold::scaffold(&scaffold::crate_dir(year), day)? {
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn fetch(args: &Args) -> Result<(), String> {
+    let year = find_year(args.positional(1, "year")?)?;
+
+    fetch::fetch(&Config::load()?, year, args.optional_positional(2, "day")?)
+}
+
+fn submit(args: &Args) -> Result<(), String> {
+    let year = find_year(args.positional(1, "year")?)?;
+    let day: u8 = args.positional(2, "day")?;
+    let part: Part = args.positional(3, "part")?;
+    let config = Config::load()?;
+
+    let answer = match args.optional_positional::<String>(4, "answer")? {
+        Some(answer) => answer,
+        None => {
+            let solution = year
+                .day(day)
+                .ok_or_else(|| format!("No solution for {} day {}", year.year, day))?;
+            let solution = (solution.parse)(&read_input(&config.input_path(year, day))?)?;
+
+            solution
+                .solve(part)
+                .ok_or_else(|| format!("Part {} is not solved yet", part))?
+        }
+    };
+
+    let mut answers = Answers::load(year.answers_path)?;
+    let verdict = submit::submit(
+        &config,
+        year.year,
+        &mut answers,
+        (day, part),
+        &answer,
+        thread::sleep,
+    )?;
+    answers.save(year.answers_path)?;
+
+    println!("Part {}: {} is {}", part, answer, verdict);
+
+    Ok(())
+}
+
+fn verify(args: &Args) -> Result<(), String> {
+    let years = selected_years(args)?;
+
+    match verify::verify(
+        &Config::load()?,
+        &years,
+        args.optional_positional(2, "day")?,
+    )? {
+        true => Ok(()),
+        false => Err("Answers no longer match the recorded answers".to_string()),
+    }
+}
+
+fn readme() -> Result<(), String> {
+    let path = readme::README_PATH;
+    let current =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let generated = readme::generate(&current, &YEARS.iter().collect::<Vec<_>>())?;
+
+    fs::write(path, &generated).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    println!(
+        "{} README.md",
+        if generated == current {
+            "Unchanged"
+        } else {
+            "Updated"
+        }
+    );
+
+    Ok(())
+}
+
+/// Benchmarks the days selected by the arguments, using the --runs and --max-time limits
+fn bench_selected(args: &Args) -> Result<Vec<bench::DayTimings>, String> {
+    let years = selected_years(args)?;
+    let limits = bench::Limits {
+        runs: args.option("runs")?.unwrap_or(10),
+        max_time: Duration::try_from_secs_f64(args.option("max-time")?.unwrap_or(5.0))
+            .map_err(|e| format!("Invalid max time: {}", e))?,
+    };
+
+    bench::bench(
+        &Config::load()?,
+        &years,
+        args.optional_positional(2, "day")?,
+        limits,
+    )
+}
+
+fn bench(args: &Args) -> Result<(), String> {
+    let format = args.option::<String>("format")?;
+    let results = bench_selected(args)?;
+
+    match format.as_deref() {
+        None | Some("table") => print!("{}", bench::table(&results)),
+        Some("json") => println!("{}", bench::json(&results)),
+        Some(format) => return Err(format!("Unknown format: {}", format)),
+    }
+
+    Ok(())
+}
+
+fn baseline_path(args: &Args) -> Result<String, String> {
+    let machine = args.option("machine")?.unwrap_or_else(baseline::machine);
+
+    Ok(baseline::baseline_path(&machine))
+}
+
+fn save_baseline(args: &Args) -> Result<(), String> {
+    let path = baseline_path(args)?;
+    let mut baseline = Baseline::load(&path)?;
+    let results = bench_selected(args)?;
+
+    print!("{}", bench::table(&results));
+    baseline.record(&results);
+    baseline.save(&path)?;
+    println!("\nSaved baseline to {}", path);
+
+    Ok(())
+}
+
+fn compare(args: &Args) -> Result<(), String> {
+    let path = baseline_path(args)?;
+    let baseline = Baseline::load(&path)?;
+    let threshold = args.option("threshold")?.unwrap_or(10.0);
+    let results = bench_selected(args)?;
+
+    match baseline::report(&baseline::compare(&baseline, &results), threshold) {
+        0 => Ok(()),
+        regressions => Err(format!(
+            "{} stages got slower than the baseline in {}",
+            regressions, path
+        )),
+    }
+}
+
+/// Options each command accepts, commands that are not listed take none
+const OPTIONS: &[(&str, &[&str])] = &[
+    ("run", &["part", "input"]),
+    (
+        "render",
+        &[
+            "input", "format", "scale", "every", "from", "to", "delay", "output",
+        ],
+    ),
+    ("bench", &["runs", "max-time", "format"]),
+    ("baseline", &["runs", "max-time", "machine"]),
+    ("compare", &["runs", "max-time", "machine", "threshold"]),
+];
+
+fn dispatch(args: &[String]) -> Result<(), String> {
+    let args = Args::parse(args)?;
+    let command = args.positional::<String>(0, "command");
+    let known = OPTIONS
+        .iter()
+        .find(|(name, _)| command.as_deref() == Ok(*name))
+        .map_or(&[][..], |(_, options)| *options);
+    args.check_options(known)
+        .map_err(|e| format!("{}\n\n{}", e, USAGE))?;
+
+    match command.as_deref() {
+        Ok("run") => run(&args),
+        Ok("render") => render_frames(&args),
+        Ok("new") => new(&args),
+        Ok("fetch") => fetch(&args),
+        Ok("submit") => submit(&args),
+        Ok("verify") => verify(&args),
+        Ok("readme") => readme(),
+        Ok("bench") => bench(&args),
+        Ok("baseline") => save_baseline(&args),
+        Ok("compare") => compare(&args),
+        Ok("help") | Err(_) => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Ok(command) => Err(format!("Unknown command: {}\n\n{}", command, USAGE)),
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match dispatch(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registered_days() {
+        for year in YEARS {
+            for (i, day) in year.days.iter().enumerate() {
+                assert_eq!(i + 1, day.day as usize, "{} days out of order", year.year);
+            }
+        }
+
+        assert_eq!(15, find_year(2023).unwrap().days.len());
+        assert!(find_year(2022).is_err());
+    }
+}
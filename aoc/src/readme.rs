@@ -0,0 +1,260 @@
+use aoc_common::answers::Answers;
+use aoc_common::puzzle::{Part, Year};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::ops::Range;
+use std::str::FromStr;
+
+pub const README_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../README.md");
+
+/// What was learned while solving the days of one year
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Learnings {
+    pub constraints: Option<String>,
+    pub days: BTreeMap<u8, String>,
+}
+
+impl Learnings {
+    /// Loads the learnings from the given file, a missing file means nothing was written down yet
+    pub fn load(path: &str) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents.parse().map_err(|e| format!("{}: {}", path, e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Failed to read {}: {}", path, e)),
+        }
+    }
+}
+
+impl FromStr for Learnings {
+    type Err = String;
+
+    /// One `<day> <learnings>` or `constraints <text>` per line, empty lines and lines starting
+    /// with `#` are ignored
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut learnings = Self::default();
+
+        for (i, line) in s.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || format!("Invalid learnings on line {}: {}", i + 1, line);
+            let (key, text) = line.split_once(' ').ok_or_else(invalid)?;
+            let text = text.trim().to_string();
+
+            match key {
+                "constraints" => learnings.constraints = Some(text),
+                day => {
+                    learnings
+                        .days
+                        .insert(day.parse().map_err(|_| invalid())?, text);
+                }
+            }
+        }
+
+        Ok(learnings)
+    }
+}
+
+/// Number of stars that can be earned in the given year, which has 12 days since 2025
+fn max_stars(year: u16) -> usize {
+    match year {
+        ..2025 => 50,
+        _ => 24,
+    }
+}
+
+/// Number of stars earned, which are the parts with an accepted answer
+pub fn stars(year: &Year, answers: &Answers) -> usize {
+    year.days
+        .iter()
+        .flat_map(|day| Part::ALL.map(|part| (day, part)))
+        .filter(|(day, part)| answers.accepted(day.day, *part).is_some())
+        .count()
+}
+
+/// Stars table of the registered years, keeping the rows of years without solutions in this
+/// repository from the current table
+pub fn stars_table(current: &str, stars: &[(u16, usize)]) -> String {
+    let mut rows = current
+        .lines()
+        .filter_map(|line| {
+            let (year, stars) = line.trim_matches('|').split_once('|')?;
+            Some((year.trim().parse::<u16>().ok()?, stars.trim().to_string()))
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    for &(year, count) in stars {
+        rows.insert(year, format!("{}/{}", count, max_stars(year)));
+    }
+
+    let mut table = "| Year 📅 | Stars ⭐ |\n| -- | -- |\n".to_string();
+    for (year, stars) in rows.iter().rev() {
+        writeln!(table, "| {} | {} |", year, stars).unwrap();
+    }
+
+    table
+}
+
+/// Learnings section per year, newest first, skipping years without learnings
+pub fn learnings_sections(learnings: &[(u16, Learnings)]) -> String {
+    let mut sections = learnings
+        .iter()
+        .filter(|(_, learnings)| !learnings.days.is_empty())
+        .collect::<Vec<_>>();
+    sections.sort_by_key(|(year, _)| std::cmp::Reverse(*year));
+
+    let sections = sections
+        .into_iter()
+        .map(|(year, learnings)| {
+            let mut section = format!("#### {}\n\n", year);
+            if let Some(constraints) = &learnings.constraints {
+                writeln!(section, "Constraints: {}\n", constraints).unwrap();
+            }
+
+            section.push_str("| Day | Main learnings |\n| -- | -- |\n");
+            for (day, text) in &learnings.days {
+                writeln!(section, "| [{day}]({year}/src/day{day}.rs) | {text} |").unwrap();
+            }
+
+            section
+        })
+        .collect::<Vec<_>>();
+
+    sections.join("\n")
+}
+
+/// Byte range of the contents between the `<!-- <name>:start -->` and `<!-- <name>:end -->`
+/// markers
+fn section_range(readme: &str, name: &str) -> Result<Range<usize>, String> {
+    let start_marker = format!("<!-- {}:start -->\n", name);
+    let end_marker = format!("<!-- {}:end -->", name);
+
+    let start = readme
+        .find(&start_marker)
+        .ok_or_else(|| format!("Missing marker {}", start_marker.trim()))?
+        + start_marker.len();
+    let end = readme[start..]
+        .find(&end_marker)
+        .ok_or_else(|| format!("Missing marker {}", end_marker))?
+        + start;
+
+    Ok(start..end)
+}
+
+pub fn section<'a>(readme: &'a str, name: &str) -> Result<&'a str, String> {
+    Ok(&readme[section_range(readme, name)?])
+}
+
+/// Replaces the contents between the markers of the given section
+pub fn replace_section(readme: &str, name: &str, contents: &str) -> Result<String, String> {
+    let mut readme = readme.to_string();
+    readme.replace_range(section_range(&readme, name)?, contents);
+
+    Ok(readme)
+}
+
+/// Regenerates the stars and learnings tables of the README from the accepted answers and the
+/// learnings of each year
+pub fn generate(readme: &str, years: &[&Year]) -> Result<String, String> {
+    let stars = years
+        .iter()
+        .map(|year| Ok((year.year, stars(year, &Answers::load(year.answers_path)?))))
+        .collect::<Result<Vec<_>, String>>()?;
+    let learnings = years
+        .iter()
+        .map(|year| Ok((year.year, Learnings::load(year.learnings_path)?)))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let readme = replace_section(
+        readme,
+        "stars",
+        &stars_table(section(readme, "stars")?, &stars),
+    )?;
+
+    replace_section(&readme, "learnings", &learnings_sections(&learnings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::parse::ParseError;
+    use aoc_common::puzzle::{Day, Solution, Unsolved};
+
+    struct Half;
+
+    impl Solution for Half {
+        type Answer1 = u8;
+        type Answer2 = Unsolved;
+
+        fn parse(_: &str) -> Result<Self, ParseError> {
+            Ok(Self)
+        }
+
+        fn part_1(&self) -> Self::Answer1 {
+            1
+        }
+
+        fn part_2(&self) -> Self::Answer2 {
+            Unsolved
+        }
+    }
+
+    const YEAR: Year = Year {
+        year: 2025,
+        input_dir: "input",
+        answers_path: "answers.txt",
+        learnings_path: "learnings.txt",
+        days: &[Day::new::<Half>(1), Day::new::<Half>(2)],
+    };
+
+    #[test]
+    fn test_stars() {
+        assert_eq!(0, stars(&YEAR, &Answers::default()));
+        assert_eq!(
+            1,
+            stars(&YEAR, &"2 2 too-low 5\n2 2 correct 6".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_stars_table() {
+        assert_eq!(
+            "| Year 📅 | Stars ⭐ |\n| -- | -- |\n| 2025 | 3/24 |\n| 2024 | 30/50 |\n| 2022 | 12/50 |\n",
+            stars_table(
+                "| Year 📅 | Stars ⭐ |\n| -- | -- |\n| 2025 | 1/24 |\n| 2022 | 12/50 |\n",
+                &[(2025, 3), (2024, 30)]
+            )
+        );
+    }
+
+    #[test]
+    fn test_learnings() {
+        let learnings =
+            "# <day> <learnings>\nconstraints none\n2 Using `FromStr`\n1 Modular arithmetic\n"
+                .parse::<Learnings>()
+                .unwrap();
+
+        assert_eq!(Some("none".to_string()), learnings.constraints);
+        assert_eq!(
+            "#### 2025\n\nConstraints: none\n\n| Day | Main learnings |\n| -- | -- |\n\
+             | [1](2025/src/day1.rs) | Modular arithmetic |\n\
+             | [2](2025/src/day2.rs) | Using `FromStr` |\n",
+            learnings_sections(&[(2025, learnings), (2024, Learnings::default())])
+        );
+        assert!("one Modular arithmetic".parse::<Learnings>().is_err());
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = "# Title\n<!-- stars:start -->\nold\n<!-- stars:end -->\nrest\n";
+
+        assert_eq!(Ok("old\n"), section(readme, "stars"));
+        assert_eq!(
+            Ok("# Title\n<!-- stars:start -->\nnew\n<!-- stars:end -->\nrest\n".to_string()),
+            replace_section(readme, "stars", "new\n")
+        );
+        assert!(section(readme, "learnings").is_err());
+    }
+}
@@ -0,0 +1,232 @@
+use aoc_common::puzzle::Parts;
+use aoc_common::render::{self, Frame, Gif};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+/// How rendered frames are output
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Format {
+    /// Animated in the terminal
+    Ansi,
+    /// An image file per frame
+    Ppm,
+    Png,
+    /// A single animated image
+    Gif,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ansi" => Ok(Self::Ansi),
+            "ppm" => Ok(Self::Ppm),
+            "png" => Ok(Self::Png),
+            "gif" => Ok(Self::Gif),
+            _ => Err(format!(
+                "Unknown format: {} (expected ansi, ppm, png or gif)",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub format: Format,
+    /// Size of the square each cell is scaled up to
+    pub scale: usize,
+    /// Only every n-th frame is output, to keep long simulations manageable
+    pub every: usize,
+    /// Number of the first frame that is output
+    pub from: usize,
+    /// Number of the last frame that is output, if any
+    pub to: Option<usize>,
+    /// Time each frame is shown in the terminal or the animation
+    pub delay: Duration,
+    /// Directory the images are written to, or the file of the animation
+    pub output: PathBuf,
+}
+
+/// Renders the simulation of the parsed solution, returns the number of frames that were output
+pub fn render(solution: &dyn Parts, options: &Options) -> Result<usize, String> {
+    if options.scale == 0 || options.every == 0 {
+        return Err("Scale and every must be at least 1".to_string());
+    }
+    let dir = match options.format {
+        Format::Ansi => None,
+        Format::Gif => options.output.parent(),
+        _ => Some(options.output.as_path()),
+    };
+    if let Some(dir) = dir.filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+
+    // Hundredths of a second, the unit of GIF delays
+    let mut gif = Gif::new(
+        (options.delay.as_millis() / 10)
+            .try_into()
+            .unwrap_or(u16::MAX),
+    );
+    let mut rendered = 0;
+    let mut output = 0;
+    let mut error = None;
+    solution.render(&mut |frame| {
+        rendered += 1;
+        let number = rendered - 1;
+        if error.is_some()
+            || number < options.from
+            || options.to.is_some_and(|to| number > to)
+            || !(number - options.from).is_multiple_of(options.every)
+        {
+            return;
+        }
+
+        let frame = render::scale(&frame, options.scale);
+        let result = match options.format {
+            Format::Gif => gif.add_frame(&frame),
+            _ => output_frame(&frame, output, options),
+        };
+        match result {
+            Ok(()) => output += 1,
+            Err(e) => error = Some(e),
+        }
+    });
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+    if options.format == Format::Gif && output > 0 {
+        fs::write(&options.output, gif.finish())
+            .map_err(|e| format!("Failed to write {}: {}", options.output.display(), e))?;
+    }
+
+    Ok(output)
+}
+
+fn output_frame(frame: &Frame, number: usize, options: &Options) -> Result<(), String> {
+    let image = match options.format {
+        Format::Ansi => {
+            // Clear the screen once, after which every frame overwrites the previous one
+            let clear = if number == 0 { "\x1b[2J" } else { "" };
+            print!("{}\x1b[H{}", clear, render::ansi(frame));
+            io::stdout()
+                .flush()
+                .map_err(|e| format!("Failed to write frame: {}", e))?;
+            thread::sleep(options.delay);
+
+            return Ok(());
+        }
+        Format::Ppm => render::ppm(frame),
+        _ => render::png(frame),
+    };
+
+    let path = frame_path(&options.output, number, options.format);
+    fs::write(&path, image).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Numbered image file of a frame, padded so the files sort in the order of the frames
+fn frame_path(dir: &Path, number: usize, format: Format) -> PathBuf {
+    let extension = match format {
+        Format::Ppm => "ppm",
+        _ => "png",
+    };
+
+    dir.join(format!("frame{:05}.{}", number, extension))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::parse::ParseError;
+    use aoc_common::puzzle::{Solution, Unsolved};
+    use aoc_common::render::Rgb;
+    use std::env;
+
+    /// Counts up to the input, one frame per number
+    struct Count(usize);
+
+    impl Solution for Count {
+        type Answer1 = Unsolved;
+        type Answer2 = Unsolved;
+
+        fn parse(input: &str) -> Result<Self, ParseError> {
+            Ok(Self(input.parse().unwrap()))
+        }
+
+        fn part_1(&self) -> Self::Answer1 {
+            Unsolved
+        }
+
+        fn part_2(&self) -> Self::Answer2 {
+            Unsolved
+        }
+
+        fn render(&self, frame: &mut dyn FnMut(Frame)) {
+            for i in 0..self.0 {
+                frame(Frame::filled(i + 1, 1, Rgb::WHITE));
+            }
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let dir = env::temp_dir().join(format!("aoc-render-{}", std::process::id()));
+        let options = Options {
+            format: Format::Ppm,
+            scale: 2,
+            every: 2,
+            from: 0,
+            to: None,
+            delay: Duration::ZERO,
+            output: dir.clone(),
+        };
+
+        assert_eq!(Ok(3), render(&Count(5), &options));
+        assert_eq!(
+            render::ppm(&Frame::filled(10, 2, Rgb::WHITE)),
+            fs::read(dir.join("frame00002.ppm")).unwrap()
+        );
+        assert!(!dir.join("frame00003.ppm").exists());
+        assert!(
+            render(
+                &Count(5),
+                &Options {
+                    every: 0,
+                    ..options.clone()
+                }
+            )
+            .is_err()
+        );
+
+        // Only frame 3 is in range, as the frames of an animation have to be of the same size
+        let gif = dir.join("count.gif");
+        let options = Options {
+            format: Format::Gif,
+            from: 3,
+            to: Some(4),
+            output: gif.clone(),
+            ..options
+        };
+        assert_eq!(Ok(1), render(&Count(10), &options));
+        assert!(fs::read(&gif).unwrap().starts_with(b"GIF89a\x08\0\x02\0"));
+        assert!(
+            render(
+                &Count(10),
+                &Options {
+                    to: Some(5),
+                    ..options
+                }
+            )
+            .is_err()
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
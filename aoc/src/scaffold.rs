@@ -0,0 +1,225 @@
+use aoc_common::puzzle::Year;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Skeleton of a new day, following the 2025 days
+const TEMPLATE: &str = "use aoc_common::parse::ParseError;
+use aoc_common::puzzle::{Solution, Unsolved};
+use std::str::FromStr;
+
+pub struct Problem {}
+
+impl Solution for Problem {
+    type Answer1 = Unsolved;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(&self) -> Self::Answer1 {
+        Unsolved
+    }
+
+    fn part_2(&self) -> Self::Answer2 {
+        Unsolved
+    }
+}
+
+impl FromStr for Problem {
+    type Err = ParseError;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {})
+    }
+}
+";
+
+/// Fixture without answers yet, the sample and its answers are filled in from the puzzle
+const FIXTURE: &str = "---\n";
+
+/// Directory of the crate with the solutions of the given year, which is where its answers file
+/// lives
+pub fn crate_dir(year: &Year) -> PathBuf {
+    Path::new(year.answers_path)
+        .parent()
+        .map_or_else(|| PathBuf::from("."), Path::to_path_buf)
+}
+
+/// Adds the `mod` declaration and the registration of the day's `Problem` to the given `lib.rs`,
+/// keeping both in the order of the existing entries
+pub fn register(lib: &str, day: u8) -> Result<String, String> {
+    let module = format!("mod day{};", day);
+    let registration = format!("        Day::new::<day{}::Problem>({}),", day, day);
+    let lines = lib.lines().collect::<Vec<_>>();
+
+    if lines.contains(&module.as_str()) {
+        return Err(format!("Day {} is already registered", day));
+    }
+
+    let modules = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("mod day"))
+        .map(|(i, line)| (i, *line))
+        .collect::<Vec<_>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let (_, rest) = line.trim().strip_prefix("Day::new::<")?.split_once(">(")?;
+            Some((i, rest.strip_suffix("),")?.parse::<u8>().ok()?))
+        })
+        .collect::<Vec<_>>();
+    let (Some(&(last_module, _)), Some(&(last_day, _))) = (modules.last(), days.last()) else {
+        return Err("No registered days to add the new day to".to_string());
+    };
+
+    // Modules are sorted by name, days by number
+    let module_at = modules
+        .iter()
+        .find(|(_, line)| line.trim_end_matches(';') > module.trim_end_matches(';'))
+        .map_or(last_module + 1, |&(i, _)| i);
+    let day_at = days
+        .iter()
+        .find(|&&(_, registered)| registered > day)
+        .map_or(last_day + 1, |&(i, _)| i);
+
+    let mut lines = lines
+        .into_iter()
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>();
+    lines.insert(day_at, registration);
+    lines.insert(module_at, module);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates the solution file and an empty fixture for the day in the given crate directory and
+/// registers the day in its `lib.rs`. Nothing is written if any of it exists already.
+pub fn scaffold(dir: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let source = dir.join(format!("src/day{}.rs", day));
+    let fixture = dir.join(format!("fixtures/day{}.txt", day));
+    let lib = dir.join("src/lib.rs");
+
+    for path in [&source, &fixture] {
+        if path.exists() {
+            return Err(format!("{} already exists", path.display()));
+        }
+    }
+
+    let registered = register(
+        &fs::read_to_string(&lib)
+            .map_err(|e| format!("Failed to read {}: {}", lib.display(), e))?,
+        day,
+    )?;
+
+    let write = |path: &Path, contents: &str| {
+        fs::write(path, contents).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    };
+    fs::create_dir_all(dir.join("fixtures"))
+        .map_err(|e| format!("Failed to create fixtures directory: {}", e))?;
+    write(&source, TEMPLATE)?;
+    write(&fixture, FIXTURE)?;
+    write(&lib, &registered)?;
+
+    Ok(vec![source, fixture, lib])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const LIB: &str = "mod day1;
+mod day10;
+mod day2;
+
+use aoc_common::puzzle::{Day, Year};
+
+pub const YEAR: Year = Year {
+    days: &[
+        Day::new::<day1::Problem>(1),
+        Day::new::<day2::Problem>(2),
+        Day::new::<day10::Problem>(10),
+    ],
+};
+";
+
+    #[test]
+    fn test_register() {
+        assert_eq!(
+            Ok("mod day1;
+mod day10;
+mod day11;
+mod day2;
+
+use aoc_common::puzzle::{Day, Year};
+
+pub const YEAR: Year = Year {
+    days: &[
+        Day::new::<day1::Problem>(1),
+        Day::new::<day2::Problem>(2),
+        Day::new::<day10::Problem>(10),
+        Day::new::<day11::Problem>(11),
+    ],
+};
+"
+            .to_string()),
+            register(LIB, 11)
+        );
+        assert_eq!(
+            Ok("mod day1;
+mod day10;
+mod day2;
+mod day3;
+
+use aoc_common::puzzle::{Day, Year};
+
+pub const YEAR: Year = Year {
+    days: &[
+        Day::new::<day1::Problem>(1),
+        Day::new::<day2::Problem>(2),
+        Day::new::<day3::Problem>(3),
+        Day::new::<day10::Problem>(10),
+    ],
+};
+"
+            .to_string()),
+            register(LIB, 3)
+        );
+        assert!(register(LIB, 2).is_err());
+        assert!(register("", 1).is_err());
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/lib.rs"), LIB).unwrap();
+
+        assert_eq!(3, scaffold(&dir, 11).unwrap().len());
+        assert_eq!(
+            TEMPLATE,
+            fs::read_to_string(dir.join("src/day11.rs")).unwrap()
+        );
+        assert_eq!(
+            FIXTURE,
+            fs::read_to_string(dir.join("fixtures/day11.txt")).unwrap()
+        );
+        assert_eq!(
+            register(LIB, 11).unwrap(),
+            fs::read_to_string(dir.join("src/lib.rs")).unwrap()
+        );
+
+        // Existing days are never overwritten
+        fs::write(dir.join("src/day11.rs"), "solved").unwrap();
+        assert!(scaffold(&dir, 11).is_err());
+        assert_eq!(
+            "solved",
+            fs::read_to_string(dir.join("src/day11.rs")).unwrap()
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
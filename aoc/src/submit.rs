@@ -0,0 +1,249 @@
+use crate::config::Config;
+use crate::http;
+use aoc_common::answers::{Answers, Attempt, Verdict};
+use aoc_common::puzzle::Part;
+use std::time::Duration;
+
+/// How often a submission is retried after waiting out a cooldown
+const MAX_ATTEMPTS: usize = 3;
+
+/// What the answer page said about a submitted answer
+#[derive(Debug, Eq, PartialEq)]
+pub enum Reply {
+    Verdict(Verdict),
+    /// An answer was given too recently, the answer can be submitted again after this long
+    Cooldown(Duration),
+    /// The part is already solved, or not unlocked yet
+    WrongLevel,
+}
+
+/// Parses the answer page returned after submitting an answer
+pub fn parse_reply(page: &str) -> Result<Reply, String> {
+    if page.contains("That's the right answer") {
+        Ok(Reply::Verdict(Verdict::Correct))
+    } else if page.contains("That's not the right answer") {
+        Ok(Reply::Verdict(
+            if page.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if page.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            },
+        ))
+    } else if page.contains("You gave an answer too recently") {
+        parse_cooldown(page)
+            .map(Reply::Cooldown)
+            .ok_or_else(|| "Rate limited, but the page doesn't say for how long".to_string())
+    } else if page.contains("You don't seem to be solving the right level") {
+        Ok(Reply::WrongLevel)
+    } else {
+        Err("Unrecognized answer page".to_string())
+    }
+}
+
+/// Parses the wait time out of a sentence like `You have 1m 5s left to wait.`
+fn parse_cooldown(page: &str) -> Option<Duration> {
+    let (start, _) = page.split_once(" left to wait")?;
+    let (_, wait) = start.rsplit_once("You have ")?;
+
+    wait.split_whitespace()
+        .map(|amount| {
+            let unit = amount.find(|c: char| !c.is_ascii_digit())?;
+            let value = amount[..unit].parse::<u64>().ok()?;
+
+            match &amount[unit..] {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Percent-encodes everything but unreserved characters, so answers like `6,1,3` survive
+fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Submits the answer unless its verdict can be derived from earlier attempts, and records the
+/// verdict in `answers`. Cooldowns are waited out using `sleep` before retrying.
+pub fn submit(
+    config: &Config,
+    year: u16,
+    answers: &mut Answers,
+    (day, part): (u8, Part),
+    answer: &str,
+    sleep: impl Fn(Duration),
+) -> Result<Verdict, String> {
+    match answers.check(day, part, answer) {
+        Some(Verdict::Correct) => return Ok(Verdict::Correct),
+        Some(verdict) => {
+            return Err(format!(
+                "Not submitting {}, it is known to be {}",
+                answer, verdict
+            ));
+        }
+        None => {}
+    }
+
+    let url = format!("{}/{}/day/{}/answer", config.base_url, year, day);
+    let form = format!("level={}&answer={}", part, encode(answer));
+
+    for _ in 0..MAX_ATTEMPTS {
+        let response = http::request(&url, config.session()?, Some(&form))?;
+        if response.status != 200 {
+            return Err(format!(
+                "Failed to submit to {}: status {}",
+                url, response.status
+            ));
+        }
+
+        match parse_reply(&response.body)? {
+            Reply::Verdict(verdict) => {
+                answers.record(Attempt {
+                    day,
+                    part,
+                    verdict,
+                    answer: answer.to_string(),
+                });
+
+                return Ok(verdict);
+            }
+            Reply::Cooldown(wait) => {
+                println!("Answered too recently, waiting {}s", wait.as_secs());
+                sleep(wait);
+            }
+            Reply::WrongLevel => {
+                return Err(format!(
+                    "Part {} of day {} is already solved or not unlocked yet",
+                    part, day
+                ));
+            }
+        }
+    }
+
+    Err(format!(
+        "Still rate limited after {} attempts",
+        MAX_ATTEMPTS
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::tests::{response, serve};
+    use std::cell::RefCell;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_parse_reply() {
+        assert_eq!(
+            Ok(Reply::Verdict(Verdict::Correct)),
+            parse_reply(&page(
+                "That's the right answer! You are one gold star closer."
+            ))
+        );
+        assert_eq!(
+            Ok(Reply::Verdict(Verdict::TooHigh)),
+            parse_reply(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            ))
+        );
+        assert_eq!(
+            Ok(Reply::Verdict(Verdict::TooLow)),
+            parse_reply(&page(
+                "That's not the right answer; your answer is too low."
+            ))
+        );
+        assert_eq!(
+            Ok(Reply::Verdict(Verdict::Wrong)),
+            parse_reply(&page("That's not the right answer. If you're stuck, ..."))
+        );
+        assert_eq!(
+            Ok(Reply::Cooldown(Duration::from_secs(65))),
+            parse_reply(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again. You have 1m 5s left to wait."
+            ))
+        );
+        assert_eq!(
+            Ok(Reply::WrongLevel),
+            parse_reply(&page(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ))
+        );
+        assert!(parse_reply(&page("Puzzle inputs differ by user.")).is_err());
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!("6%2C1%2C3", encode("6,1,3"));
+        assert_eq!("abc-123", encode("abc-123"));
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, server) = serve(vec![
+            response(
+                200,
+                &page("You gave an answer too recently. You have 30s left to wait."),
+            ),
+            response(
+                200,
+                &page("That's not the right answer; your answer is too low."),
+            ),
+            response(200, &page("That's the right answer!")),
+        ]);
+        let config = Config::parse("session = abc", |name| match name {
+            "AOC_BASE_URL" => Some(url.clone()),
+            _ => None,
+        })
+        .unwrap();
+        let mut answers = Answers::default();
+        let waited = RefCell::new(vec![]);
+        let sleep = |wait| waited.borrow_mut().push(wait);
+
+        assert_eq!(
+            Ok(Verdict::TooLow),
+            submit(&config, 2024, &mut answers, (2, Part::Two), "549", sleep)
+        );
+        assert_eq!(vec![Duration::from_secs(30)], *waited.borrow());
+
+        // Known to be too low, so these are refused without asking the server
+        assert!(submit(&config, 2024, &mut answers, (2, Part::Two), "549", sleep).is_err());
+        assert!(submit(&config, 2024, &mut answers, (2, Part::Two), "500", sleep).is_err());
+
+        assert_eq!(
+            Ok(Verdict::Correct),
+            submit(&config, 2024, &mut answers, (2, Part::Two), "569", sleep)
+        );
+        assert_eq!(Some("569"), answers.accepted(2, Part::Two));
+        assert_eq!(
+            Ok(Verdict::Correct),
+            submit(&config, 2024, &mut answers, (2, Part::Two), "569", sleep)
+        );
+
+        let requests = server.join().unwrap();
+        assert_eq!(3, requests.len());
+        assert!(requests[0].starts_with("POST /2024/day/2/answer HTTP/1.1\r\n"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=2&answer=549"));
+        assert!(requests[2].ends_with("\r\n\r\nlevel=2&answer=569"));
+    }
+}
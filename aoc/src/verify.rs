@@ -0,0 +1,133 @@
+use crate::config::Config;
+use aoc_common::answers::{Answers, Verdict};
+use aoc_common::puzzle::{Day, Part, Year};
+use std::fs;
+
+/// Outcome of verifying one part of a day against its recorded answers
+#[derive(Debug, Eq, PartialEq)]
+pub enum Outcome {
+    /// The answer matches the accepted answer
+    Ok(String),
+    /// No accepted answer has been recorded, and the answer was not rejected before either
+    Unverified(String),
+    /// The answer differs from the accepted answer, or was rejected before
+    Failed(String),
+    Unsolved,
+}
+
+pub fn verify_part(answers: &Answers, day: u8, part: Part, answer: Option<String>) -> Outcome {
+    let Some(answer) = answer else {
+        return Outcome::Unsolved;
+    };
+
+    match (
+        answers.check(day, part, &answer),
+        answers.accepted(day, part),
+    ) {
+        (Some(Verdict::Correct), _) => Outcome::Ok(answer),
+        (Some(_), Some(accepted)) => {
+            Outcome::Failed(format!("expected {}, got {}", accepted, answer))
+        }
+        (Some(verdict), None) => {
+            Outcome::Failed(format!("got {}, which was rejected as {}", answer, verdict))
+        }
+        (None, _) => Outcome::Unverified(answer),
+    }
+}
+
+/// Solves both parts of the day, or describes why the day could not be verified
+fn verify_day(
+    config: &Config,
+    year: &Year,
+    day: &Day,
+    answers: &Answers,
+) -> Result<[Outcome; 2], String> {
+    let path = config.input_path(year, day.day);
+    let input = fs::read_to_string(&path).map_err(|_| format!("missing input {}", path))?;
+    let solution = (day.parse)(&input).map_err(|e| format!("failed to parse input: {}", e))?;
+
+    Ok(Part::ALL.map(|part| verify_part(answers, day.day, part, solution.solve(part))))
+}
+
+/// Re-runs the given days of each year and compares them with the recorded answers. Returns
+/// whether all answers that could be verified are still correct.
+pub fn verify(config: &Config, years: &[&Year], day: Option<u8>) -> Result<bool, String> {
+    let (mut ok, mut failed, mut skipped) = (0, 0, 0);
+
+    for year in years {
+        let answers = Answers::load(year.answers_path)?;
+
+        for solution in year
+            .days
+            .iter()
+            .filter(|d| day.is_none_or(|day| d.day == day))
+        {
+            let outcomes = match verify_day(config, year, solution, &answers) {
+                Ok(outcomes) => outcomes,
+                Err(reason) => {
+                    println!("{} day {:>2}: skipped, {}", year.year, solution.day, reason);
+                    skipped += 2;
+                    continue;
+                }
+            };
+
+            for (part, outcome) in Part::ALL.iter().zip(outcomes) {
+                let status = match outcome {
+                    Outcome::Ok(answer) => {
+                        ok += 1;
+                        format!("ok ({})", answer)
+                    }
+                    Outcome::Unverified(answer) => {
+                        skipped += 1;
+                        format!("no answer recorded ({})", answer)
+                    }
+                    Outcome::Failed(reason) => {
+                        failed += 1;
+                        format!("FAILED, {}", reason)
+                    }
+                    Outcome::Unsolved => {
+                        skipped += 1;
+                        "not solved yet".to_string()
+                    }
+                };
+
+                println!(
+                    "{} day {:>2} part {}: {}",
+                    year.year, solution.day, part, status
+                );
+            }
+        }
+    }
+
+    println!("\n{} ok, {} failed, {} skipped", ok, failed, skipped);
+
+    Ok(failed == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_part() {
+        let answers = "2 1 correct 524\n2 2 too-low 549\n".parse().unwrap();
+
+        assert_eq!(
+            Outcome::Ok("524".to_string()),
+            verify_part(&answers, 2, Part::One, Some("524".to_string()))
+        );
+        assert_eq!(
+            Outcome::Failed("expected 524, got 525".to_string()),
+            verify_part(&answers, 2, Part::One, Some("525".to_string()))
+        );
+        assert_eq!(
+            Outcome::Failed("got 500, which was rejected as too-low".to_string()),
+            verify_part(&answers, 2, Part::Two, Some("500".to_string()))
+        );
+        assert_eq!(
+            Outcome::Unverified("569".to_string()),
+            verify_part(&answers, 2, Part::Two, Some("569".to_string()))
+        );
+        assert_eq!(Outcome::Unsolved, verify_part(&answers, 2, Part::Two, None));
+    }
+}
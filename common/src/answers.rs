@@ -0,0 +1,247 @@
+use crate::puzzle::Part;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::str::FromStr;
+
+/// Outcome of submitting an answer. Rejected answers don't always come with a hint, in which case
+/// they are just `Wrong`.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Self::Correct),
+            "too-high" => Ok(Self::TooHigh),
+            "too-low" => Ok(Self::TooLow),
+            "wrong" => Ok(Self::Wrong),
+            _ => Err(format!("Unknown verdict: {}", s)),
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too-high"),
+            Self::TooLow => write!(f, "too-low"),
+            Self::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+/// A single answer given for one part of a day, along with its verdict
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+impl FromStr for Attempt {
+    type Err = String;
+
+    /// Parses a line formatted as `<day> <part> <verdict> <answer>`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut columns = s.splitn(4, ' ');
+        let mut column = |name| {
+            columns
+                .next()
+                .filter(|column| !column.is_empty())
+                .ok_or_else(|| format!("Missing {}", name))
+        };
+
+        Ok(Self {
+            day: column("day")?
+                .parse()
+                .map_err(|_| "Invalid day".to_string())?,
+            part: column("part")?.parse()?,
+            verdict: column("verdict")?.parse()?,
+            answer: column("answer")?.trim().to_string(),
+        })
+    }
+}
+
+impl Display for Attempt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.day, self.part, self.verdict, self.answer
+        )
+    }
+}
+
+/// All answers given for the puzzles of one year, in the order they were given
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Answers {
+    attempts: Vec<Attempt>,
+}
+
+impl Answers {
+    /// Loads the answers from the given file, a missing file means no answers were given yet
+    pub fn load(path: &str) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents.parse().map_err(|e| format!("{}: {}", path, e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Failed to read {}: {}", path, e)),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        fs::write(path, self.to_string()).map_err(|e| format!("Failed to write {}: {}", path, e))
+    }
+
+    pub fn attempts(&self, day: u8, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |attempt| attempt.day == day && attempt.part == part)
+    }
+
+    pub fn accepted(&self, day: u8, part: Part) -> Option<&str> {
+        self.attempts(day, part)
+            .find(|attempt| attempt.verdict == Verdict::Correct)
+            .map(|attempt| attempt.answer.as_str())
+    }
+
+    pub fn rejected(&self, day: u8, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts(day, part)
+            .filter(|attempt| attempt.verdict != Verdict::Correct)
+    }
+
+    /// Verdict for the given answer that can be derived from earlier attempts without submitting
+    /// it. Numeric answers beyond a rejected too high or too low attempt are rejected as well.
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Option<Verdict> {
+        if let Some(accepted) = self.accepted(day, part) {
+            return Some(match accepted == answer {
+                true => Verdict::Correct,
+                false => Verdict::Wrong,
+            });
+        }
+
+        let number = answer.parse::<i128>().ok();
+
+        self.rejected(day, part).find_map(|attempt| {
+            let bound = attempt.answer.parse::<i128>().ok();
+
+            match (attempt.verdict, number, bound) {
+                _ if attempt.answer == answer => Some(attempt.verdict),
+                (Verdict::TooHigh, Some(n), Some(bound)) if n >= bound => Some(Verdict::TooHigh),
+                (Verdict::TooLow, Some(n), Some(bound)) if n <= bound => Some(Verdict::TooLow),
+                _ => None,
+            }
+        })
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+}
+
+impl FromStr for Answers {
+    type Err = String;
+
+    /// One attempt per line, empty lines and lines starting with `#` are ignored
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            attempts: s
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+                .map(|(i, line)| {
+                    line.parse()
+                        .map_err(|e| format!("Invalid attempt on line {}: {}", i + 1, e))
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# <day> <part> <verdict> <answer>")?;
+        writeln!(f, "# verdict: correct, too-high, too-low or wrong")?;
+
+        for attempt in &self.attempts {
+            writeln!(f, "{}", attempt)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"# <day> <part> <verdict> <answer>
+2 1 correct 524
+2 2 too-low 549
+2 2 too-low 554
+
+8 1 too-high 304
+8 1 wrong 280
+9 1 correct 6,1,3
+"#;
+
+    #[test]
+    fn test_parse() {
+        let answers = SAMPLE.parse::<Answers>().unwrap();
+
+        assert_eq!(Some("524"), answers.accepted(2, Part::One));
+        assert_eq!(None, answers.accepted(2, Part::Two));
+        assert_eq!(Some("6,1,3"), answers.accepted(9, Part::One));
+        assert_eq!(2, answers.rejected(2, Part::Two).count());
+        assert_eq!(
+            Attempt {
+                day: 2,
+                part: Part::Two,
+                verdict: Verdict::TooLow,
+                answer: "549".to_string()
+            },
+            *answers.rejected(2, Part::Two).next().unwrap()
+        );
+
+        assert!("2 1 correct".parse::<Answers>().is_err());
+        assert!("2 3 correct 524".parse::<Answers>().is_err());
+        assert!("2 1 close 524".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = SAMPLE.parse::<Answers>().unwrap();
+
+        assert_eq!(Some(Verdict::Correct), answers.check(2, Part::One, "524"));
+        assert_eq!(Some(Verdict::Wrong), answers.check(2, Part::One, "525"));
+        assert_eq!(Some(Verdict::TooLow), answers.check(2, Part::Two, "554"));
+        assert_eq!(Some(Verdict::TooLow), answers.check(2, Part::Two, "100"));
+        assert_eq!(None, answers.check(2, Part::Two, "555"));
+        assert_eq!(Some(Verdict::TooHigh), answers.check(8, Part::One, "305"));
+        assert_eq!(Some(Verdict::Wrong), answers.check(8, Part::One, "280"));
+        assert_eq!(None, answers.check(8, Part::One, "281"));
+        assert_eq!(None, answers.check(3, Part::One, "1"));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut answers = SAMPLE.parse::<Answers>().unwrap();
+        answers.record(Attempt {
+            day: 2,
+            part: Part::Two,
+            verdict: Verdict::Correct,
+            answer: "569".to_string(),
+        });
+
+        assert_eq!(Some("569"), answers.accepted(2, Part::Two));
+        assert_eq!(answers, answers.to_string().parse().unwrap());
+    }
+}
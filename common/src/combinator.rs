@@ -0,0 +1,206 @@
+use crate::parse::{self, ParseError};
+use std::str::FromStr;
+
+/// Parses the start of the input, returning the parsed value and the rest of the input
+///
+/// Any function or closure with the right signature is a parser. Errors point at the rest of the
+/// input where parsing failed, so they are located like any other `ParseError`.
+pub trait Parser<'a, T>: Fn(&'a str) -> Result<(T, &'a str), ParseError> + Sized {
+    /// Parser transforming the parsed value with the given function
+    fn map<U>(self, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+        move |input: &'a str| self(input).map(|(value, rest)| (f(value), rest))
+    }
+
+    /// Parses the whole input, only allowing trailing whitespace after the parsed value
+    fn parse_all(&self, input: &'a str) -> Result<T, ParseError> {
+        let (value, rest) = self(input)?;
+
+        match rest.trim_end().is_empty() {
+            true => Ok(value),
+            false => Err(ParseError::at(excerpt(rest), "Unexpected input")),
+        }
+    }
+}
+
+impl<'a, T, F> Parser<'a, T> for F where F: Fn(&'a str) -> Result<(T, &'a str), ParseError> {}
+
+/// Rest of the line at the start of the input, to show in errors
+fn excerpt(input: &str) -> &str {
+    &input[..input.find('\n').unwrap_or(input.len())]
+}
+
+/// Parses the given literal
+pub fn literal<'a>(literal: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(literal) {
+        Some(rest) => Ok((&input[..literal.len()], rest)),
+        None => Err(ParseError::at(
+            excerpt(input),
+            format!("Expected {:?}", literal),
+        )),
+    }
+}
+
+/// Parses an integer with an optional sign
+pub fn integer<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        let sign = input.starts_with(['-', '+']) as usize;
+        let end = input[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(input.len(), |i| sign + i);
+
+        if end == sign {
+            return Err(ParseError::at(excerpt(input), "Expected an integer"));
+        }
+
+        let (digits, rest) = input.split_at(end);
+        Ok((parse::number(digits)?, rest))
+    }
+}
+
+/// Parses a run of letters, digits and underscores, like a label
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    move |input: &'a str| match input.find(|c: char| !c.is_alphanumeric() && c != '_') {
+        Some(0) => Err(ParseError::at(excerpt(input), "Expected a word")),
+        Some(end) => Ok(input.split_at(end)),
+        None if input.is_empty() => Err(ParseError::at(input, "Expected a word")),
+        None => Ok((input, "")),
+    }
+}
+
+/// Parses both parsers one after the other
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input: &'a str| {
+        let (a, rest) = first(input)?;
+        let (b, rest) = second(rest)?;
+
+        Ok(((a, b), rest))
+    }
+}
+
+/// Parses the value after the given prefix
+pub fn preceded<'a, T>(prefix: &'static str, parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    pair(literal(prefix), parser).map(|(_, value)| value)
+}
+
+/// Parses the value before the given suffix
+pub fn terminated<'a, T>(parser: impl Parser<'a, T>, suffix: &'static str) -> impl Parser<'a, T> {
+    pair(parser, literal(suffix)).map(|(value, _)| value)
+}
+
+/// Parses two values with the given separator in between, like the `3-5` of a range
+pub fn separated_pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    separator: &'static str,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    pair(terminated(first, separator), second)
+}
+
+/// Parses one or more values with the given separator in between
+pub fn separated<'a, T>(
+    item: impl Parser<'a, T>,
+    separator: &'static str,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = item(input)?;
+        let mut items = vec![first];
+
+        while let Some(next) = rest.strip_prefix(separator) {
+            let (value, after) = item(next)?;
+            items.push(value);
+            rest = after;
+        }
+
+        Ok((items, rest))
+    }
+}
+
+/// Parses one or more values on consecutive lines, stopping at a blank line
+pub fn lines<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    repeated(item, "\n")
+}
+
+/// Parses one or more sections separated by blank lines
+pub fn sections<'a, T>(section: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    repeated(section, "\n\n")
+}
+
+/// Like `separated`, but stops when only whitespace or another blank line follows the separator
+fn repeated<'a, T>(item: impl Parser<'a, T>, separator: &'static str) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = item(input)?;
+        let mut items = vec![first];
+
+        while let Some(next) = rest
+            .strip_prefix(separator)
+            .filter(|next| !next.trim_end().is_empty() && !next.starts_with('\n'))
+        {
+            let (value, after) = item(next)?;
+            items.push(value);
+            rest = after;
+        }
+
+        Ok((items, rest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primitives() {
+        assert_eq!(Ok(("p=", "1")), literal("p=")("p=1"));
+        assert_eq!(Ok((-12, ",3")), integer::<i32>()("-12,3"));
+        assert_eq!(Ok((94, "")), integer::<u32>()("+94"));
+        assert_eq!(Ok(("aaa", ": b")), word()("aaa: b"));
+        assert_eq!(
+            Ok(((3, 5), "")),
+            separated_pair(integer::<u8>(), "-", integer())("3-5")
+        );
+        assert_eq!(
+            Ok((vec!["b", "c"], "")),
+            preceded("a: ", separated(word(), " "))("a: b c")
+        );
+
+        assert!(literal("p=")("v=1").is_err());
+        assert!(integer::<u8>()("-").is_err());
+        assert!(word()("").is_err());
+    }
+
+    #[test]
+    fn test_grammar() {
+        let vector = || separated_pair(integer::<i64>(), ",", integer::<i64>());
+        let robot = separated_pair(preceded("p=", vector()), " ", preceded("v=", vector()));
+        let input = "p=0,4 v=3,-3\np=6,3 v=-1,-3\n\np=10,3 v=-1,2\n";
+
+        assert_eq!(
+            Ok(vec![
+                vec![((0, 4), (3, -3)), ((6, 3), (-1, -3))],
+                vec![((10, 3), (-1, 2))]
+            ]),
+            sections(lines(robot)).parse_all(input)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let input = "1,2\n3,x\n";
+        let numbers = lines(separated(integer::<u8>(), ","));
+
+        let error = numbers.parse_all(input).unwrap_err().locate(input);
+        assert_eq!(
+            "line 2, column 3: Expected an integer: \"x\"",
+            error.to_string()
+        );
+
+        let error = numbers.parse_all("1,2 3").unwrap_err();
+        assert_eq!("Unexpected input: \" 3\"", error.to_string());
+
+        let error = integer::<u8>().parse_all("300").unwrap_err();
+        assert_eq!("Invalid number: \"300\"", error.to_string());
+    }
+}
@@ -0,0 +1,274 @@
+use crate::puzzle::{Part, Year};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// A sample input along with the answers it should produce. Fixture files start with a header of
+/// `<key>: <value>` lines, followed by a `---` line and the input itself:
+///
+/// ```text
+/// part_1: 40
+/// part_2: 25272
+/// connections: 10
+/// ---
+/// 162,817,812
+/// ...
+/// ```
+///
+/// Both answers are optional, any other key is a parameter that differs between the samples and
+/// the actual input, which is passed on to `Solution::set_param`.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Fixture {
+    pub answers: [Option<String>; 2],
+    pub params: Vec<(String, String)>,
+    pub input: String,
+}
+
+impl FromStr for Fixture {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (header, input) = s
+            .split_once("---\n")
+            .filter(|(header, _)| header.is_empty() || header.ends_with('\n'))
+            .ok_or_else(|| "Missing --- line between header and input".to_string())?;
+        let mut fixture = Self {
+            input: input.to_string(),
+            ..Self::default()
+        };
+
+        for line in header.lines().filter(|line| !line.trim().is_empty()) {
+            let (key, value) = line
+                .split_once(':')
+                .map(|(key, value)| (key.trim(), value.trim().to_string()))
+                .ok_or_else(|| format!("Invalid header line: {}", line))?;
+
+            match key {
+                "part_1" => fixture.answers[0] = Some(value),
+                "part_2" => fixture.answers[1] = Some(value),
+                _ => fixture.params.push((key.to_string(), value)),
+            }
+        }
+
+        Ok(fixture)
+    }
+}
+
+impl Fixture {
+    /// Solves the fixture with the solution of the given day of the year, and compares the
+    /// answers with the expected ones
+    pub fn check(&self, year: &Year, day: u8) -> Result<(), String> {
+        let solution = year
+            .day(day)
+            .ok_or_else(|| format!("No solution for day {}", day))?;
+        let mut parts = (solution.parse)(&self.input)?;
+
+        for (name, value) in &self.params {
+            parts.set_param(name, value)?;
+        }
+
+        for (part, expected) in Part::ALL.iter().zip(&self.answers) {
+            let Some(expected) = expected else {
+                continue;
+            };
+
+            match parts.solve(*part) {
+                Some(answer) if answer == *expected => {}
+                Some(answer) => {
+                    return Err(format!(
+                        "Part {}: expected {}, got {}",
+                        part, expected, answer
+                    ));
+                }
+                None => return Err(format!("Part {}: not solved yet", part)),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Day a fixture is for, taken from its name like `day10` or `day10b`
+pub fn day(name: &str) -> Option<u8> {
+    name.strip_prefix("day")?
+        .split(|c: char| !c.is_ascii_digit())
+        .next()?
+        .parse()
+        .ok()
+}
+
+/// Generates a `fixture_test!` invocation for every fixture in the given directory, to be called
+/// from a build script. The generated file is meant to be included in a test module.
+pub fn generate_tests(dir: &str) {
+    println!("cargo:rerun-if-changed={}", dir);
+
+    let mut names = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .map(|entry| entry.expect("Failed to read fixture directory").path())
+                .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+                .map(|path| {
+                    path.file_stem()
+                        .expect("Fixture without name")
+                        .to_string_lossy()
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    names.sort();
+
+    let tests = names
+        .iter()
+        .map(|name| format!("aoc_common::fixture_test!(crate::YEAR, {});\n", name))
+        .collect::<String>();
+
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR is set for build scripts");
+    fs::write(Path::new(&out_dir).join("fixture_tests.rs"), tests)
+        .expect("Failed to write fixture tests");
+}
+
+/// Loads the fixture with the given name from the crate's `fixtures` directory
+#[macro_export]
+macro_rules! fixture {
+    ($name:literal) => {
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/",
+            $name,
+            ".txt"
+        ))
+        .parse::<$crate::fixture::Fixture>()
+        .expect(concat!("Invalid fixture ", $name))
+    };
+}
+
+/// Test that checks the answers of the fixture with the given name against the solution of its
+/// day in the given year
+#[macro_export]
+macro_rules! fixture_test {
+    ($year:expr, $name:ident) => {
+        #[test]
+        fn $name() {
+            let fixture = include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/fixtures/",
+                stringify!($name),
+                ".txt"
+            ))
+            .parse::<$crate::fixture::Fixture>()
+            .expect(concat!("Invalid fixture ", stringify!($name)));
+            let day = $crate::fixture::day(stringify!($name))
+                .expect(concat!("No day in fixture name ", stringify!($name)));
+
+            if let Err(e) = fixture.check(&$year, day) {
+                panic!("{}: {}", stringify!($name), e);
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{self, ParseError};
+    use crate::puzzle::{Day, Solution, Unsolved};
+
+    /// Sums the numbers of the input, or only the first `take` of them
+    struct Sum {
+        numbers: Vec<u32>,
+        take: usize,
+    }
+
+    impl Solution for Sum {
+        type Answer1 = u32;
+        type Answer2 = Unsolved;
+
+        fn parse(input: &str) -> Result<Self, ParseError> {
+            let numbers = parse::numbers(input)?;
+
+            Ok(Self {
+                take: numbers.len(),
+                numbers,
+            })
+        }
+
+        fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+            match name {
+                "take" => self.take = value.parse().map_err(|_| "Invalid take".to_string())?,
+                _ => return Err(format!("Unknown parameter: {}", name)),
+            }
+
+            Ok(())
+        }
+
+        fn part_1(&self) -> Self::Answer1 {
+            self.numbers.iter().take(self.take).sum()
+        }
+
+        fn part_2(&self) -> Self::Answer2 {
+            Unsolved
+        }
+    }
+
+    const YEAR: Year = Year {
+        year: 2024,
+        input_dir: "input",
+        answers_path: "answers.txt",
+        learnings_path: "learnings.txt",
+        days: &[Day::new::<Sum>(1)],
+    };
+
+    #[test]
+    fn test_parse() {
+        let fixture = "part_1: 40\ntake: 10\n---\n1\n2\n---\n"
+            .parse::<Fixture>()
+            .unwrap();
+
+        assert_eq!([Some("40".to_string()), None], fixture.answers);
+        assert_eq!(vec![("take".to_string(), "10".to_string())], fixture.params);
+        assert_eq!("1\n2\n---\n", fixture.input);
+
+        assert_eq!("1\n", "---\n1\n".parse::<Fixture>().unwrap().input);
+        assert!("part_1: 40\n1\n2\n".parse::<Fixture>().is_err());
+        assert!("part_1 40\n---\n1\n".parse::<Fixture>().is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let check = |fixture: &str| fixture.parse::<Fixture>().unwrap().check(&YEAR, 1);
+
+        assert_eq!(Ok(()), check("part_1: 6\n---\n1\n2\n3\n"));
+        assert_eq!(Ok(()), check("part_1: 3\ntake: 2\n---\n1\n2\n3\n"));
+        assert_eq!(
+            Err("Part 1: expected 5, got 6".to_string()),
+            check("part_1: 5\n---\n1\n2\n3\n")
+        );
+        assert_eq!(
+            Err("Part 2: not solved yet".to_string()),
+            check("part_2: 5\n---\n1\n")
+        );
+        assert_eq!(
+            Err("Unknown parameter: skip".to_string()),
+            check("skip: 1\n---\n1\n")
+        );
+        assert_eq!(
+            Err("line 2, column 1: Invalid number: \"one\"".to_string()),
+            check("---\n1\none\n")
+        );
+        assert!(
+            "---\n1\n"
+                .parse::<Fixture>()
+                .unwrap()
+                .check(&YEAR, 2)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_day() {
+        assert_eq!(Some(8), day("day8"));
+        assert_eq!(Some(10), day("day10b"));
+        assert_eq!(None, day("sample"));
+    }
+}
@@ -0,0 +1,305 @@
+use crate::grid::Position;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// Integer vector used for positions, velocities and dimensions on 2D maps. The y-axis points
+/// down, matching the row order of the puzzle inputs.
+#[derive(Debug, Copy, Clone, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Vec2 {
+    pub const ZERO: Self = Self::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Wraps the vector into the area spanned by the given dimensions, so negative components
+    /// wrap around to the far side (e.g. -1 becomes width - 1)
+    pub fn rem_euclid(self, dimensions: Vec2) -> Self {
+        Self::new(
+            self.x.rem_euclid(dimensions.x),
+            self.y.rem_euclid(dimensions.y),
+        )
+    }
+
+    /// Taxicab distance: number of orthogonal steps needed to reach the other vector
+    pub fn manhattan(&self, other: Vec2) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Chessboard distance: number of steps needed when diagonal steps are allowed as well
+    pub fn chebyshev(&self, other: Vec2) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Grid position for this vector, if neither component is negative
+    pub fn to_position(self) -> Option<Position> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl From<Position> for Vec2 {
+    fn from(pos: Position) -> Self {
+        Self::new(pos.0 as i64, pos.1 as i64)
+    }
+}
+
+impl Display for Vec2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add<Vec2> for Vec2 {
+    type Output = Self;
+    fn add(self, rhs: Vec2) -> Self {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign<Vec2> for Vec2 {
+    fn add_assign(&mut self, rhs: Vec2) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Vec2> for Vec2 {
+    type Output = Self;
+    fn sub(self, rhs: Vec2) -> Self {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign<Vec2> for Vec2 {
+    fn sub_assign(&mut self, rhs: Vec2) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Self;
+    fn mul(self, rhs: i64) -> Self {
+        Vec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl MulAssign<i64> for Vec2 {
+    fn mul_assign(&mut self, rhs: i64) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<i64> for Vec2 {
+    type Output = Self;
+    fn div(self, rhs: i64) -> Self {
+        Vec2::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl Div<Vec2> for Vec2 {
+    type Output = Self;
+    fn div(self, rhs: Vec2) -> Self {
+        Vec2::new(self.x / rhs.x, self.y / rhs.y)
+    }
+}
+
+impl Rem<Vec2> for Vec2 {
+    type Output = Self;
+    fn rem(self, rhs: Vec2) -> Self {
+        Vec2::new(self.x % rhs.x, self.y % rhs.y)
+    }
+}
+
+/// One of the four orthogonal directions on a map, as seen from above
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions in clockwise order, starting upwards
+    pub const ALL: [Direction; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Rotates 90 degrees clockwise
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    /// Rotates 90 degrees counterclockwise
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+        }
+    }
+
+    /// Unit step in this direction
+    pub fn vec(self) -> Vec2 {
+        match self {
+            Self::Up => Vec2::new(0, -1),
+            Self::Right => Vec2::new(1, 0),
+            Self::Down => Vec2::new(0, 1),
+            Self::Left => Vec2::new(-1, 0),
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+
+    /// Arrow character pointing in this direction, as used in puzzle inputs
+    pub fn arrow(self) -> char {
+        match self {
+            Self::Up => '^',
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Left => '<',
+        }
+    }
+}
+
+impl From<Direction> for Vec2 {
+    fn from(direction: Direction) -> Self {
+        direction.vec()
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    /// Accepts arrows (`^>v<`), relative letters (`URDL`) and compass letters (`NESW`)
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'U' | 'N' => Ok(Self::Up),
+            '>' | 'R' | 'E' => Ok(Self::Right),
+            'v' | 'D' | 'S' => Ok(Self::Down),
+            '<' | 'L' | 'W' => Ok(Self::Left),
+            _ => Err(format!("Unknown direction: {}", c)),
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vec2_ops() {
+        let mut vec = Vec2::new(1, 2);
+
+        assert_eq!(Vec2::new(4, 6), vec + Vec2::new(3, 4));
+        assert_eq!(Vec2::new(-2, -2), vec - Vec2::new(3, 4));
+        assert_eq!(Vec2::new(-1, -2), -vec);
+        assert_eq!(Vec2::new(3, -6), Vec2::new(1, -2) * 3);
+        assert_eq!(Vec2::new(5, 51), Vec2::new(11, 103) / 2);
+        assert_eq!(Vec2::new(2, 3), Vec2::new(22, 33) / Vec2::new(11, 11));
+        assert_eq!(Vec2::new(1, 2), Vec2::new(12, 13) % Vec2::new(11, 11));
+
+        vec += Vec2::new(1, 1);
+        assert_eq!(Vec2::new(2, 3), vec);
+        vec -= Vec2::new(2, 2);
+        assert_eq!(Vec2::new(0, 1), vec);
+        vec *= 5;
+        assert_eq!(Vec2::new(0, 5), vec);
+    }
+
+    #[test]
+    fn test_vec2_wrapping() {
+        let dims = Vec2::new(11, 7);
+
+        assert_eq!(Vec2::new(10, 6), Vec2::new(-1, -1).rem_euclid(dims));
+        assert_eq!(Vec2::new(1, 0), Vec2::new(12, 7).rem_euclid(dims));
+        assert_eq!(Vec2::new(3, 4), Vec2::new(3, 4).rem_euclid(dims));
+        assert_eq!(Vec2::new(0, 3), Vec2::new(-22, -18).rem_euclid(dims));
+    }
+
+    #[test]
+    fn test_vec2_distances() {
+        let origin = Vec2::ZERO;
+
+        assert_eq!(7, origin.manhattan(Vec2::new(3, -4)));
+        assert_eq!(4, origin.chebyshev(Vec2::new(3, -4)));
+        assert_eq!(0, Vec2::new(2, 2).manhattan(Vec2::new(2, 2)));
+        assert_eq!(1, Vec2::new(2, 2).chebyshev(Vec2::new(3, 3)));
+    }
+
+    #[test]
+    fn test_vec2_positions() {
+        assert_eq!(Some((3, 4)), Vec2::new(3, 4).to_position());
+        assert_eq!(None, Vec2::new(-1, 4).to_position());
+        assert_eq!(Vec2::new(3, 4), Vec2::from((3, 4)));
+    }
+
+    #[test]
+    fn test_direction_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction, direction.turn_left().turn_right());
+            assert_eq!(direction.reverse(), direction.turn_right().turn_right());
+            assert_eq!(direction.reverse(), direction.turn_left().turn_left());
+            assert_eq!(-direction.vec(), direction.reverse().vec());
+            assert_eq!(1, direction.vec().manhattan(Vec2::ZERO));
+
+            // Rotating (x,y) clockwise on a y-down map gives (-y,x)
+            let vec = direction.vec();
+            assert_eq!(Vec2::new(-vec.y, vec.x), direction.turn_right().vec());
+        }
+
+        assert_eq!(Direction::Right, Direction::Up.turn_right());
+        assert_eq!(Direction::Left, Direction::Up.turn_left());
+        assert!(Direction::Left.is_horizontal());
+        assert!(!Direction::Down.is_horizontal());
+    }
+
+    #[test]
+    fn test_direction_parsing() {
+        assert_eq!(Ok(Direction::Up), Direction::try_from('^'));
+        assert_eq!(Ok(Direction::Right), Direction::try_from('>'));
+        assert_eq!(Ok(Direction::Down), Direction::try_from('v'));
+        assert_eq!(Ok(Direction::Left), Direction::try_from('<'));
+        assert_eq!(Ok(Direction::Up), Direction::try_from('U'));
+        assert_eq!(Ok(Direction::Left), Direction::try_from('L'));
+        assert_eq!(Ok(Direction::Right), Direction::try_from('E'));
+        assert_eq!(Ok(Direction::Down), Direction::try_from('S'));
+        assert!(Direction::try_from('x').is_err());
+
+        for direction in Direction::ALL {
+            assert_eq!(Ok(direction), Direction::try_from(direction.arrow()));
+        }
+    }
+}
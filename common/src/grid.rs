@@ -0,0 +1,401 @@
+use crate::parse::{self, ParseError};
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// Cell position represented by tuple of Cartesian coordinates (x,y), origin in the top left
+pub type Position = (usize, usize);
+
+/// Rectangular grid of cells stored in row major order
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from row major cells, which must exactly fill the given dimensions
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self, String> {
+        if cells.len() != width * height {
+            return Err(format!(
+                "Expected {} cells for a {}x{} grid, got {}",
+                width * height,
+                width,
+                height,
+                cells.len()
+            ));
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses a character grid, mapping each character to a cell. Surrounding whitespace is
+    /// trimmed so raw string samples starting and ending with a newline can be used as is.
+    pub fn parse_with<E: Display>(
+        s: &str,
+        mut parse_cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for line in s.trim().lines() {
+            let line_width = line.chars().count();
+
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(ParseError::at(
+                        line,
+                        format!("Row has {} cells, expected {}", line_width, width),
+                    ));
+                }
+                _ => {}
+            }
+
+            for (i, c) in line.char_indices() {
+                cells.push(
+                    parse_cell(c)
+                        .map_err(|e| ParseError::at(parse::char_at(line, i), e.to_string()))?,
+                );
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.ok_or_else(|| ParseError::after(s.trim(), "Empty grid"))?,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.0 < self.width && pos.1 < self.height
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        if !self.contains(pos) {
+            return None;
+        }
+
+        self.cells.get(self.pos_to_idx(pos))
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        if !self.contains(pos) {
+            return None;
+        }
+
+        let idx = self.pos_to_idx(pos);
+        self.cells.get_mut(idx)
+    }
+
+    /// Like `get`, but accepts signed coordinates so callers can step off the grid freely
+    pub fn get_signed(&self, x: i64, y: i64) -> Option<&T> {
+        if x < 0 || y < 0 {
+            return None;
+        }
+
+        self.get((x as usize, y as usize))
+    }
+
+    pub fn pos_to_idx(&self, pos: Position) -> usize {
+        pos.0 + pos.1 * self.width
+    }
+
+    pub fn idx_to_pos(&self, idx: usize) -> Position {
+        (idx % self.width, idx / self.width)
+    }
+
+    /// All positions in row major order
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |idx| (idx % width, idx / width))
+    }
+
+    /// All cells with their positions in row major order
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| (self.idx_to_pos(idx), cell))
+    }
+
+    /// Position of the first cell (in row major order) matching the predicate
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|idx| self.idx_to_pos(idx))
+    }
+
+    /// Orthogonally adjacent positions within bounds, in row major order
+    pub fn neighbours_4(&self, pos: Position) -> impl Iterator<Item = Position> + use<T> {
+        self.neighbours(pos, &[(0, -1), (-1, 0), (1, 0), (0, 1)])
+    }
+
+    /// Orthogonally and diagonally adjacent positions within bounds, in row major order
+    #[rustfmt::skip]
+    pub fn neighbours_8(&self, pos: Position) -> impl Iterator<Item = Position> + use<T> {
+        self.neighbours(pos, &[
+            (-1, -1), (0, -1), (1, -1),
+            (-1, 0), (1, 0),
+            (-1, 1), (0, 1), (1, 1),
+        ])
+    }
+
+    fn neighbours(
+        &self,
+        pos: Position,
+        offsets: &'static [(i64, i64)],
+    ) -> impl Iterator<Item = Position> + use<T> {
+        let (width, height) = (self.width as i64, self.height as i64);
+
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let (x, y) = (pos.0 as i64 + dx, pos.1 as i64 + dy);
+
+            if x < 0 || y < 0 || x >= width || y >= height {
+                return None;
+            }
+
+            Some((x as usize, y as usize))
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Rows that can be changed, none for a grid without columns
+    pub fn rows_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [T]> {
+        self.cells.chunks_mut(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flat_map(|col| col.cloned()).collect(),
+        }
+    }
+
+    /// Rotates the grid 90 degrees clockwise, so the left column becomes the top row
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flat_map(|col| col.rev().cloned()).collect(),
+        }
+    }
+
+    /// Rotates the grid 90 degrees counterclockwise, so the right column becomes the top row
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().rev().flat_map(|col| col.cloned()).collect(),
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position {:?} out of grid bounds", pos))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position {:?} out of grid bounds", pos))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, Ok::<char, ParseError>)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::Location;
+
+    const SAMPLE: &str = r#"
+123
+456
+"#;
+
+    #[test]
+    fn test_parse() {
+        let grid = SAMPLE.parse::<Grid<char>>().unwrap();
+
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!(Some(&'1'), grid.get((0, 0)));
+        assert_eq!(Some(&'6'), grid.get((2, 1)));
+        assert_eq!(None, grid.get((3, 0)));
+        assert_eq!(None, grid.get((0, 2)));
+        assert_eq!(None, grid.get_signed(-1, 0));
+        assert_eq!('5', grid[(1, 1)]);
+
+        let ragged = "12\n3";
+        assert_eq!(
+            Some(Location { line: 2, column: 1 }),
+            ragged
+                .parse::<Grid<char>>()
+                .unwrap_err()
+                .locate(ragged)
+                .location
+        );
+        assert!("".parse::<Grid<char>>().is_err());
+
+        let digits = Grid::parse_with(SAMPLE, |c| c.to_digit(10).ok_or("Not a digit")).unwrap();
+        assert_eq!(Some(&4), digits.get((0, 1)));
+        assert_eq!(
+            Some("Not a digit: \"a\"".to_string()),
+            Grid::parse_with("1a", |c| c.to_digit(10).ok_or("Not a digit"))
+                .err()
+                .map(|e| e.to_string())
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = SAMPLE.parse::<Grid<char>>().unwrap();
+
+        assert_eq!(
+            vec![(1, 0), (0, 1)],
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(1, 0), (0, 1), (2, 1)],
+            grid.neighbours_4((1, 1)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(1, 0), (0, 1), (1, 1)],
+            grid.neighbours_8((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(0, 0), (1, 0), (2, 0), (0, 1), (2, 1)],
+            grid.neighbours_8((1, 1)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = SAMPLE.parse::<Grid<char>>().unwrap();
+
+        assert_eq!(&['4', '5', '6'], grid.row(1));
+        assert_eq!(2, grid.rows().count());
+        let mut reversed = grid.clone();
+        reversed.rows_mut().for_each(|row| row.reverse());
+        assert_eq!("321\n654", reversed.to_string());
+        assert_eq!(vec![&'3', &'6'], grid.column(2).collect::<Vec<_>>());
+        assert_eq!(3, grid.columns().count());
+        assert_eq!(Some((1, 1)), grid.find(|&c| c == '5'));
+
+        let mut empty = Grid::filled(0, 2, '.');
+        assert_eq!(2, empty.rows().filter(|row| row.is_empty()).count());
+        assert_eq!(0, empty.rows_mut().count());
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = SAMPLE.parse::<Grid<char>>().unwrap();
+
+        assert_eq!("14\n25\n36", grid.transpose().to_string());
+        assert_eq!("41\n52\n63", grid.rotate_cw().to_string());
+        assert_eq!("36\n25\n14", grid.rotate_ccw().to_string());
+        assert_eq!(grid, grid.rotate_cw().rotate_ccw());
+        assert_eq!(grid, (0..4).fold(grid.clone(), |acc, _| acc.rotate_cw()));
+        assert_eq!(grid, grid.transpose().transpose());
+    }
+
+    #[test]
+    fn test_display() {
+        let grid = SAMPLE.parse::<Grid<char>>().unwrap();
+
+        assert_eq!("123\n456", grid.to_string());
+        assert_eq!(
+            "246\n81012",
+            grid.map(|c| c.to_digit(10).unwrap() * 2).to_string()
+        );
+    }
+}
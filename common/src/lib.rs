@@ -0,0 +1,11 @@
+pub mod answers;
+pub mod combinator;
+pub mod fixture;
+pub mod geometry;
+pub mod grid;
+pub mod linear;
+pub mod parse;
+pub mod polyomino;
+pub mod puzzle;
+pub mod render;
+pub mod shortest_path;
@@ -0,0 +1,556 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Exact fraction in lowest terms, with a positive denominator
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Rational {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self::integer(0);
+    pub const ONE: Self = Self::integer(1);
+
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        assert_ne!(denominator, 0, "Denominator of zero");
+
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    pub const fn integer(value: i64) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    pub fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i64 {
+        self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    match b {
+        0 => a.abs(),
+        _ => gcd(b, a % b),
+    }
+}
+
+fn lcm(a: i64, b: i64) -> i64 {
+    a / gcd(a, b) * b
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Self::integer(value)
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            self.numerator * rhs.denominator + rhs.numerator * self.denominator,
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.numerator * rhs.numerator,
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        Self::new(
+            self.numerator * rhs.denominator,
+            self.denominator * rhs.numerator,
+        )
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numerator as i128 * other.denominator as i128)
+            .cmp(&(other.numerator as i128 * self.denominator as i128))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.denominator {
+            1 => write!(f, "{}", self.numerator),
+            _ => write!(f, "{}/{}", self.numerator, self.denominator),
+        }
+    }
+}
+
+/// Pivot variable of a reduced system, scaled to integers:
+/// `denominator * variable = constant - sum(coefficients[k] * free variable k)`
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct PivotRow {
+    variable: usize,
+    denominator: i64,
+    constant: i64,
+    coefficients: Vec<i64>,
+}
+
+/// Solutions of a system of linear equations `Ax = b`, brought into reduced row echelon form
+/// by Gaussian elimination. Every pivot variable is determined by the free variables.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LinearSystem {
+    variables: usize,
+    free: Vec<usize>,
+    pivots: Vec<PivotRow>,
+}
+
+impl LinearSystem {
+    /// Eliminates the equations given by the rows of coefficients and their constants. There is
+    /// none if the equations contradict each other.
+    pub fn reduce(coefficients: &[Vec<i64>], constants: &[i64]) -> Option<Self> {
+        let variables = coefficients.first().map_or(0, Vec::len);
+        let mut rows = coefficients
+            .iter()
+            .zip(constants)
+            .map(|(row, &constant)| {
+                row.iter()
+                    .chain([&constant])
+                    .map(|&value| Rational::integer(value))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut pivot_columns = vec![];
+        let mut free = vec![];
+        for column in 0..variables {
+            let row = pivot_columns.len();
+            let Some(pivot) = (row..rows.len()).find(|&i| !rows[i][column].is_zero()) else {
+                free.push(column);
+                continue;
+            };
+            rows.swap(row, pivot);
+
+            let factor = rows[row][column];
+            rows[row]
+                .iter_mut()
+                .for_each(|value| *value = *value / factor);
+            let pivot_row = rows[row].clone();
+            for (_, other) in rows.iter_mut().enumerate().filter(|&(i, _)| i != row) {
+                let factor = other[column];
+                if !factor.is_zero() {
+                    for (value, pivot_value) in other.iter_mut().zip(&pivot_row) {
+                        *value = *value - factor * *pivot_value;
+                    }
+                }
+            }
+            pivot_columns.push(column);
+        }
+
+        // Rows without a pivot are left as 0 = constant
+        if rows[pivot_columns.len()..]
+            .iter()
+            .any(|row| !row[variables].is_zero())
+        {
+            return None;
+        }
+
+        let pivots = pivot_columns
+            .iter()
+            .zip(&rows)
+            .map(|(&variable, row)| {
+                let denominator = row.iter().fold(1, |acc, value| lcm(acc, value.denominator));
+                let scale = |value: Rational| value.numerator * (denominator / value.denominator);
+
+                PivotRow {
+                    variable,
+                    denominator,
+                    constant: scale(row[variables]),
+                    coefficients: free.iter().map(|&column| scale(row[column])).collect(),
+                }
+            })
+            .collect();
+
+        Some(Self {
+            variables,
+            free,
+            pivots,
+        })
+    }
+
+    /// Variables that can take any value, in order
+    pub fn free_variables(&self) -> &[usize] {
+        &self.free
+    }
+
+    /// Values of all variables for the given values of the free ones, unless some of them are
+    /// not integers
+    pub fn integer_solution(&self, free_values: &[i64]) -> Option<Vec<i64>> {
+        let mut values = vec![0; self.variables];
+
+        for (&variable, &value) in self.free.iter().zip(free_values) {
+            values[variable] = value;
+        }
+        for row in &self.pivots {
+            let numerator = row.constant
+                - row
+                    .coefficients
+                    .iter()
+                    .zip(free_values)
+                    .map(|(coefficient, value)| coefficient * value)
+                    .sum::<i64>();
+            if numerator % row.denominator != 0 {
+                return None;
+            }
+            values[row.variable] = numerator / row.denominator;
+        }
+
+        Some(values)
+    }
+
+    /// Lowest sum of all variables over the solutions in non-negative integers, with every free
+    /// variable at most its bound. The free variables are tried one by one, cutting off the
+    /// branches that can't make every variable non-negative or beat the best sum so far.
+    pub fn minimum_non_negative_sum(&self, bounds: &[i64]) -> Option<i64> {
+        // The sum of all variables times this scale is an integer linear function of the free
+        // variables: base + sum(weights[k] * free variable k)
+        let scale = self
+            .pivots
+            .iter()
+            .fold(1, |acc, row| lcm(acc, row.denominator));
+        let base = self
+            .pivots
+            .iter()
+            .map(|row| row.constant * (scale / row.denominator))
+            .sum();
+        let weights = (0..self.free.len())
+            .map(|k| {
+                scale
+                    - self
+                        .pivots
+                        .iter()
+                        .map(|row| row.coefficients[k] * (scale / row.denominator))
+                        .sum::<i64>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut search = Search {
+            system: self,
+            bounds,
+            weights: &weights,
+            scale,
+            numerators: self.pivots.iter().map(|row| row.constant).collect(),
+            best: None,
+        };
+        search.free_variable(0, base);
+
+        search.best
+    }
+}
+
+/// Smallest set of the bit vectors whose XOR is the target, as their indices. Gaussian
+/// elimination over GF(2) gives one such set, every other one differs from it by a combination
+/// of the nullspace basis, all of which are tried. At most 64 vectors are supported.
+pub fn min_xor_combination(vectors: &[u64], target: u64) -> Option<Vec<usize>> {
+    assert!(vectors.len() <= 64, "Too many vectors");
+
+    // An equation per bit: the vectors having that bit set, and whether the target has it
+    let mut rows = (0..64)
+        .map(|bit| {
+            let vars = (0..vectors.len())
+                .filter(|&i| vectors[i] >> bit & 1 == 1)
+                .fold(0u64, |acc, i| acc | 1 << i);
+            (vars, target >> bit & 1 == 1)
+        })
+        .filter(|&(vars, constant)| vars != 0 || constant)
+        .collect::<Vec<_>>();
+
+    let mut pivots = vec![];
+    let mut free = vec![];
+    for column in 0..vectors.len() {
+        let row = pivots.len();
+        let Some(pivot) = (row..rows.len()).find(|&i| rows[i].0 >> column & 1 == 1) else {
+            free.push(column);
+            continue;
+        };
+        rows.swap(row, pivot);
+
+        let (pivot_vars, pivot_constant) = rows[row];
+        for (_, other) in rows.iter_mut().enumerate().filter(|&(i, _)| i != row) {
+            if other.0 >> column & 1 == 1 {
+                *other = (other.0 ^ pivot_vars, other.1 ^ pivot_constant);
+            }
+        }
+        pivots.push(column);
+    }
+    if rows[pivots.len()..].iter().any(|&(_, constant)| constant) {
+        return None;
+    }
+
+    // Solution with all free vectors left out, and a nullspace vector per free vector
+    let solution = pivots
+        .iter()
+        .zip(&rows)
+        .filter(|(_, (_, constant))| *constant)
+        .fold(0u64, |acc, (&column, _)| acc | 1 << column);
+    let nullspace = free
+        .iter()
+        .map(|&column| {
+            pivots
+                .iter()
+                .zip(&rows)
+                .filter(|(_, (vars, _))| vars >> column & 1 == 1)
+                .fold(1u64 << column, |acc, (&pivot, _)| acc | 1 << pivot)
+        })
+        .collect::<Vec<_>>();
+
+    // Every subset of the nullspace basis, up to all 64 vectors without overflowing
+    let last = u64::MAX
+        .checked_shr(64 - nullspace.len() as u32)
+        .unwrap_or(0);
+    let best = (0..=last)
+        .map(|combination| {
+            (0..nullspace.len())
+                .filter(|&i| combination >> i & 1 == 1)
+                .fold(solution, |acc, i| acc ^ nullspace[i])
+        })
+        .min_by_key(|selection| selection.count_ones())?;
+
+    Some((0..vectors.len()).filter(|&i| best >> i & 1 == 1).collect())
+}
+
+/// State of the branch and bound search for the lowest sum
+struct Search<'a> {
+    system: &'a LinearSystem,
+    bounds: &'a [i64],
+    weights: &'a [i64],
+    scale: i64,
+    /// Numerators of the pivot variables for the free variables chosen so far
+    numerators: Vec<i64>,
+    best: Option<i64>,
+}
+
+impl Search<'_> {
+    fn free_variable(&mut self, k: usize, scaled_sum: i64) {
+        let pivots = &self.system.pivots;
+
+        if k == self.bounds.len() {
+            let is_solution = pivots
+                .iter()
+                .zip(&self.numerators)
+                .all(|(row, &numerator)| numerator >= 0 && numerator % row.denominator == 0);
+            if is_solution && self.best.is_none_or(|best| scaled_sum < best * self.scale) {
+                self.best = Some(scaled_sum / self.scale);
+            }
+            return;
+        }
+
+        // Give up when a pivot variable stays negative even for the most favourable values of
+        // the remaining free variables, or when the sum can't get below the best one
+        let can_be_non_negative = pivots
+            .iter()
+            .zip(&self.numerators)
+            .all(|(row, &numerator)| {
+                let increase = (k..self.bounds.len())
+                    .map(|j| (-row.coefficients[j] * self.bounds[j]).max(0))
+                    .sum::<i64>();
+                numerator + increase >= 0
+            });
+        let lowest_sum = scaled_sum
+            + (k..self.bounds.len())
+                .map(|j| (self.weights[j] * self.bounds[j]).min(0))
+                .sum::<i64>();
+        if !can_be_non_negative
+            || self
+                .best
+                .is_some_and(|best| lowest_sum >= best * self.scale)
+        {
+            return;
+        }
+
+        for value in 0..=self.bounds[k] {
+            self.free_variable(k + 1, scaled_sum + self.weights[k] * value);
+
+            for (row, numerator) in pivots.iter().zip(self.numerators.iter_mut()) {
+                *numerator -= row.coefficients[k];
+            }
+        }
+        for (row, numerator) in pivots.iter().zip(self.numerators.iter_mut()) {
+            *numerator += row.coefficients[k] * (self.bounds[k] + 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rational() {
+        let half = Rational::new(1, 2);
+        let third = Rational::new(-2, -6);
+
+        assert_eq!(Rational::new(5, 6), half + third);
+        assert_eq!(Rational::new(1, 6), half - third);
+        assert_eq!(Rational::new(1, 6), half * third);
+        assert_eq!(Rational::new(-3, 2), half / -third);
+        assert_eq!(
+            (4, 1),
+            (
+                Rational::new(8, 2).numerator(),
+                Rational::new(8, 2).denominator()
+            )
+        );
+        assert!(third < half);
+        assert_eq!("-3/2", Rational::new(3, -2).to_string());
+        assert_eq!("7", Rational::from(7).to_string());
+    }
+
+    #[test]
+    fn test_unique_solution() {
+        // 2x + y = 5, x - y = -2
+        let system = LinearSystem::reduce(&[vec![2, 1], vec![1, -1]], &[5, -2]).unwrap();
+
+        assert!(system.free_variables().is_empty());
+        assert_eq!(Some(vec![1, 3]), system.integer_solution(&[]));
+
+        // 2x = 3 has no integer solution
+        let system = LinearSystem::reduce(&[vec![2]], &[3]).unwrap();
+        assert_eq!(None, system.integer_solution(&[]));
+
+        // x + y = 1, x + y = 2
+        assert_eq!(
+            None,
+            LinearSystem::reduce(&[vec![1, 1], vec![1, 1]], &[1, 2])
+        );
+    }
+
+    #[test]
+    fn test_free_variables() {
+        // x + z = 3, y + 2z = 4, and a redundant row
+        let system =
+            LinearSystem::reduce(&[vec![1, 0, 1], vec![0, 1, 2], vec![1, 1, 3]], &[3, 4, 7])
+                .unwrap();
+
+        assert_eq!(&[2], system.free_variables());
+        assert_eq!(Some(vec![2, 2, 1]), system.integer_solution(&[1]));
+        assert_eq!(Some(3), system.minimum_non_negative_sum(&[3]));
+        assert_eq!(Some(7), system.minimum_non_negative_sum(&[0]));
+
+        // 2x + y = 3: x is only an integer for an odd y
+        let system = LinearSystem::reduce(&[vec![2, 1]], &[3]).unwrap();
+        assert_eq!(&[1], system.free_variables());
+        assert_eq!(None, system.integer_solution(&[0]));
+        assert_eq!(Some(2), system.minimum_non_negative_sum(&[3]));
+        assert_eq!(None, system.minimum_non_negative_sum(&[0]));
+    }
+
+    #[test]
+    fn test_min_xor_combination() {
+        let vectors = [0b1000, 0b1010, 0b0100, 0b1100, 0b0101, 0b0011];
+
+        // 0b0110 is 0b1010 ^ 0b1100 or 0b0101 ^ 0b0011, but never a single vector
+        let combination = min_xor_combination(&vectors, 0b0110).unwrap();
+        assert_eq!(2, combination.len());
+        assert_eq!(
+            0b0110,
+            combination.iter().fold(0, |acc, &i| acc ^ vectors[i])
+        );
+
+        assert_eq!(Some(vec![2]), min_xor_combination(&vectors, 0b0100));
+        assert_eq!(Some(vec![]), min_xor_combination(&vectors, 0));
+        assert_eq!(None, min_xor_combination(&vectors, 0b10000));
+        assert_eq!(None, min_xor_combination(&[0b11, 0b11], 0b01));
+
+        // Without a nullspace, and with every vector in it
+        assert_eq!(Some(vec![0]), min_xor_combination(&[0b1], 0b1));
+        assert_eq!(Some(vec![]), min_xor_combination(&[0, 0, 0], 0));
+    }
+
+    #[test]
+    fn test_minimum_non_negative_sum() {
+        // Systems of 0/1 coefficients compared to trying all values of all variables
+        let mut seed = 7u64;
+        let mut random = |n: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) % n
+        };
+
+        for _ in 0..50 {
+            let (equations, variables) = (random(3) as usize + 2, random(2) as usize + 3);
+            let coefficients = (0..equations)
+                .map(|_| (0..variables).map(|_| random(2) as i64).collect())
+                .collect::<Vec<Vec<i64>>>();
+            let values = (0..variables).map(|_| random(3) as i64).collect::<Vec<_>>();
+            let constants = coefficients
+                .iter()
+                .map(|row| row.iter().zip(&values).map(|(a, x)| a * x).sum())
+                .collect::<Vec<i64>>();
+
+            // No variable exceeds the largest constant, unless its coefficients are all zero
+            let limit = constants.iter().max().unwrap() + 1;
+            let mut expected = None::<i64>;
+            for i in 0..limit.pow(variables as u32) {
+                let values = (0..variables as u32)
+                    .map(|k| i / limit.pow(k) % limit)
+                    .collect::<Vec<_>>();
+                let solves = coefficients.iter().zip(&constants).all(|(row, &constant)| {
+                    row.iter().zip(&values).map(|(a, x)| a * x).sum::<i64>() == constant
+                });
+                if solves {
+                    let sum = values.iter().sum();
+                    expected = Some(expected.map_or(sum, |best| best.min(sum)));
+                }
+            }
+
+            let system = LinearSystem::reduce(&coefficients, &constants).unwrap();
+            let bounds = vec![limit; system.free_variables().len()];
+            assert_eq!(expected, system.minimum_non_negative_sum(&bounds));
+        }
+    }
+}
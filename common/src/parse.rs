@@ -0,0 +1,151 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Line and column of a position in the input, both starting at 1. Columns count characters.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Error while parsing a puzzle input, pointing at the offending snippet of the input.
+///
+/// Parsers work on slices of the input (as returned by `lines`, `split`, `trim`, ...), so an
+/// error only needs the slice it is about. Where that slice is in the input is worked out once
+/// the error reaches the parser of the whole input, see `locate`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    pub snippet: String,
+    pub location: Option<Location>,
+    /// Memory address of the snippet, used to find it in the input
+    address: usize,
+}
+
+impl ParseError {
+    /// Error about the given snippet, which must be a slice of the input to be located
+    pub fn at(snippet: &str, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            snippet: snippet.to_string(),
+            location: None,
+            address: snippet.as_ptr() as usize,
+        }
+    }
+
+    /// Error about what is missing right after the given slice of the input, like the end of a
+    /// truncated input
+    pub fn after(part: &str, message: impl Into<String>) -> Self {
+        Self::at(&part[part.len()..], message)
+    }
+
+    /// Finds the snippet in the input the error was raised for, unless it was located already
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if self.location.is_some() || !(start..=start + input.len()).contains(&self.address) {
+            return self;
+        }
+
+        let before = &input[..self.address - start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        self.location = Some(Location {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        });
+
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(location) = self.location {
+            write!(f, "line {}, column {}: ", location.line, location.column)?;
+        }
+
+        match self.snippet.is_empty() {
+            true => write!(f, "{}", self.message),
+            false => write!(f, "{}: {:?}", self.message, self.snippet),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseError> for String {
+    fn from(error: ParseError) -> Self {
+        error.to_string()
+    }
+}
+
+/// Parses the trimmed token, naming `what` was expected if it can't be parsed
+pub fn token<T: FromStr>(token: &str, what: &str) -> Result<T, ParseError> {
+    let token = token.trim();
+
+    token
+        .parse()
+        .map_err(|_| ParseError::at(token, format!("Invalid {}", what)))
+}
+
+/// Parses the trimmed token as a number
+pub fn number<T: FromStr>(token: &str) -> Result<T, ParseError> {
+    self::token(token, "number")
+}
+
+/// Parses every whitespace separated number of the given slice
+pub fn numbers<T: FromStr>(s: &str) -> Result<Vec<T>, ParseError> {
+    s.split_whitespace().map(number).collect()
+}
+
+/// Splits the slice at the first occurrence of the delimiter, which must be present
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(s, format!("Missing {:?}", delimiter)))
+}
+
+/// Slice of the given character in the string, for errors about single characters
+pub fn char_at(s: &str, index: usize) -> &str {
+    let end = s[index..]
+        .chars()
+        .next()
+        .map_or(index, |c| index + c.len_utf8());
+
+    &s[index..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        let input = "1 2\n3 x\n";
+        let line = input.lines().nth(1).unwrap();
+
+        let error = numbers::<u8>(line).unwrap_err().locate(input);
+        assert_eq!(Some(Location { line: 2, column: 3 }), error.location);
+        assert_eq!("line 2, column 3: Invalid number: \"x\"", error.to_string());
+
+        let error = ParseError::after(input.trim_end(), "Missing moves").locate(input);
+        assert_eq!("line 2, column 4: Missing moves", error.to_string());
+
+        // Snippets that are not part of the input keep their message only
+        let error = number::<u8>("x").unwrap_err().locate(input);
+        assert_eq!(None, error.location);
+        assert_eq!("Invalid number: \"x\"", error.to_string());
+    }
+
+    #[test]
+    fn test_helpers() {
+        assert_eq!(Ok(12), number::<u8>(" 12 "));
+        assert_eq!(Ok(vec![1, 2, 3]), numbers::<u8>("1  2 3"));
+        assert_eq!(Ok(("a", "b")), split_once("a: b", ": "));
+        assert_eq!(
+            "Missing \": \": \"a b\"",
+            split_once("a b", ": ").unwrap_err().to_string()
+        );
+        assert_eq!("é", char_at("aéb", 1));
+        assert_eq!("", char_at("ab", 2));
+    }
+}
@@ -0,0 +1,291 @@
+use crate::grid::{Grid, Position};
+use std::collections::HashSet;
+
+/// Shape made of cells, stored as a grid that is true for the cells of the shape and trimmed
+/// to its bounding box
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct Polyomino {
+    shape: Grid<bool>,
+}
+
+impl Polyomino {
+    /// Shape of the cells that are true in the grid, leaving out empty rows and columns around
+    /// it
+    pub fn new(grid: &Grid<bool>) -> Self {
+        let cells = grid
+            .iter()
+            .filter(|&(_, &filled)| filled)
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>();
+        let (left, top) = cells.iter().fold((usize::MAX, usize::MAX), |(x, y), pos| {
+            (x.min(pos.0), y.min(pos.1))
+        });
+        let (right, bottom) = cells
+            .iter()
+            .fold((0, 0), |(x, y), pos| (x.max(pos.0 + 1), y.max(pos.1 + 1)));
+
+        let mut shape = Grid::filled(
+            right.saturating_sub(left),
+            bottom.saturating_sub(top),
+            false,
+        );
+        for (x, y) in cells {
+            shape[(x - left, y - top)] = true;
+        }
+
+        Self { shape }
+    }
+
+    pub fn width(&self) -> usize {
+        self.shape.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.shape.height()
+    }
+
+    /// Number of cells
+    pub fn area(&self) -> usize {
+        self.shape.iter().filter(|&(_, &filled)| filled).count()
+    }
+
+    /// Cells of the shape in row major order, relative to the top left of its bounding box
+    pub fn cells(&self) -> impl Iterator<Item = Position> + '_ {
+        self.shape
+            .iter()
+            .filter(|&(_, &filled)| filled)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn rotate_cw(&self) -> Self {
+        Self {
+            shape: self.shape.rotate_cw(),
+        }
+    }
+
+    /// Mirror image, flipped over the diagonal from the top left
+    pub fn reflect(&self) -> Self {
+        Self {
+            shape: self.shape.transpose(),
+        }
+    }
+
+    /// Distinct shapes after rotating and reflecting
+    pub fn orientations(&self) -> Vec<Self> {
+        let mut seen = HashSet::new();
+
+        [self.clone(), self.reflect()]
+            .into_iter()
+            .flat_map(|shape| {
+                (0..4).scan(shape, |shape, _| {
+                    let current = shape.clone();
+                    *shape = shape.rotate_cw();
+                    Some(current)
+                })
+            })
+            .filter(|shape| seen.insert(shape.clone()))
+            .collect()
+    }
+
+    /// Whether the shape fits on the empty cells of the grid with its top left at the origin
+    pub fn fits<T>(&self, grid: &Grid<Option<T>>, (x, y): Position) -> bool {
+        self.cells()
+            .all(|(dx, dy)| matches!(grid.get((x + dx, y + dy)), Some(None)))
+    }
+}
+
+/// Packs the given numbers of each shape into a region of the given size, with each shape
+/// rotated and reflected as needed. Returns the region with the index of the shape covering
+/// each cell, unless they don't fit. Every shape needs at least one cell.
+pub fn pack(
+    width: usize,
+    height: usize,
+    shapes: &[(&Polyomino, usize)],
+) -> Option<Grid<Option<usize>>> {
+    let mut region = Grid::filled(width, height, None);
+    let needed = shapes
+        .iter()
+        .map(|(shape, count)| shape.area() * count)
+        .sum::<usize>();
+    if needed > width * height {
+        return None;
+    }
+
+    // When every shape gets a box of its own, the shapes are packed without search
+    let box_width = shapes
+        .iter()
+        .map(|(shape, _)| shape.width())
+        .max()
+        .unwrap_or(0);
+    let box_height = shapes
+        .iter()
+        .map(|(shape, _)| shape.height())
+        .max()
+        .unwrap_or(0);
+    let boxes = match (box_width, box_height) {
+        (0, _) | (_, 0) => usize::MAX,
+        _ => (width / box_width) * (height / box_height),
+    };
+    if shapes.iter().map(|(_, count)| count).sum::<usize>() <= boxes {
+        let pieces = shapes
+            .iter()
+            .enumerate()
+            .flat_map(|(index, &(shape, count))| (0..count).map(move |_| (index, shape)));
+        for (i, (index, shape)) in pieces.enumerate() {
+            let origin = (
+                i % (width / box_width) * box_width,
+                i / (width / box_width) * box_height,
+            );
+            place(&mut region, shape, origin, Some(index));
+        }
+
+        return Some(region);
+    }
+
+    // Deciding the cells along the shorter side first keeps the search front small
+    if width > height {
+        return pack(height, width, shapes).map(|region| region.transpose());
+    }
+
+    let mut search = Packing {
+        orientations: shapes
+            .iter()
+            .map(|(shape, _)| shape.orientations())
+            .collect(),
+        remaining: shapes.iter().map(|&(_, count)| count).collect(),
+        region,
+    };
+    search
+        .fill(0, width * height - needed)
+        .then_some(search.region)
+}
+
+fn place(
+    region: &mut Grid<Option<usize>>,
+    shape: &Polyomino,
+    (x, y): Position,
+    value: Option<usize>,
+) {
+    for (dx, dy) in shape.cells() {
+        region[(x + dx, y + dy)] = value;
+    }
+}
+
+/// Backtracking search that decides the cells of the region in row major order: the first
+/// undecided cell is either covered by the first cell of a shape, or stays empty as long as
+/// enough room is left for the remaining shapes.
+struct Packing {
+    orientations: Vec<Vec<Polyomino>>,
+    remaining: Vec<usize>,
+    region: Grid<Option<usize>>,
+}
+
+impl Packing {
+    fn fill(&mut self, from: usize, spare: usize) -> bool {
+        if self.remaining.iter().all(|&count| count == 0) {
+            return true;
+        }
+        let Some(index) = (from..self.region.width() * self.region.height())
+            .find(|&index| self.region[self.region.idx_to_pos(index)].is_none())
+        else {
+            return false;
+        };
+        let (x, y) = self.region.idx_to_pos(index);
+
+        for shape in 0..self.orientations.len() {
+            if self.remaining[shape] == 0 {
+                continue;
+            }
+
+            for orientation in 0..self.orientations[shape].len() {
+                let piece = &self.orientations[shape][orientation];
+                let (first_x, first_y) = piece.cells().next().unwrap();
+                if first_x > x || first_y > y {
+                    continue;
+                }
+
+                let origin = (x - first_x, y - first_y);
+                if !piece.fits(&self.region, origin) {
+                    continue;
+                }
+
+                place(&mut self.region, piece, origin, Some(shape));
+                self.remaining[shape] -= 1;
+                if self.fill(index + 1, spare) {
+                    return true;
+                }
+                self.remaining[shape] += 1;
+                place(
+                    &mut self.region,
+                    &self.orientations[shape][orientation],
+                    origin,
+                    None,
+                );
+            }
+        }
+
+        spare > 0 && self.fill(index + 1, spare - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polyomino(s: &str) -> Polyomino {
+        Polyomino::new(&Grid::parse_with(s, |char| Ok::<_, &str>(char == '#')).unwrap())
+    }
+
+    #[test]
+    fn test_shape() {
+        let l = polyomino(
+            r#"
+....
+.#..
+.#..
+.##.
+"#,
+        );
+
+        assert_eq!((2, 3, 4), (l.width(), l.height(), l.area()));
+        assert_eq!(
+            vec![(0, 0), (0, 1), (0, 2), (1, 2)],
+            l.cells().collect::<Vec<_>>()
+        );
+        assert_eq!(polyomino("###\n#.."), l.rotate_cw());
+        assert_eq!(polyomino("###\n..#"), l.reflect());
+        assert_eq!(8, l.orientations().len());
+
+        assert_eq!(1, polyomino("##\n##").orientations().len());
+        assert_eq!(2, polyomino("####").orientations().len());
+        assert_eq!(4, polyomino("###\n.#.").orientations().len());
+    }
+
+    #[test]
+    fn test_pack() {
+        let l = polyomino("#.\n#.\n##");
+        let square = polyomino("##\n##");
+
+        // Two L shapes make a 2x4 rectangle, and together with a square a 4x3 one
+        let packed = pack(2, 4, &[(&l, 2)]).unwrap();
+        assert!(packed.iter().all(|(_, &cell)| cell == Some(0)));
+        assert!(pack(4, 3, &[(&l, 2), (&square, 1)]).is_some());
+
+        // Enough room, but not in the right places
+        assert_eq!(None, pack(3, 3, &[(&square, 2)]));
+        assert_eq!(None, pack(3, 5, &[(&square, 3)]));
+
+        let packed = pack(4, 4, &[(&l, 2), (&square, 2)]).unwrap();
+        assert_eq!(
+            (8, 8),
+            (
+                packed.iter().filter(|&(_, &cell)| cell == Some(0)).count(),
+                packed.iter().filter(|&(_, &cell)| cell == Some(1)).count()
+            )
+        );
+
+        // Every shape in a box of its own
+        let packed = pack(6, 7, &[(&l, 3), (&square, 3)]).unwrap();
+        assert_eq!(Some(1), packed[(4, 3)]);
+    }
+}
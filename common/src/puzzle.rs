@@ -0,0 +1,253 @@
+use crate::parse::ParseError;
+use crate::render::Frame;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// One of the two parts every puzzle consists of
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Self::One, Self::Two];
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(format!("Invalid part: {} (expected 1 or 2)", s)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A day's puzzle: parsed once from the input, after which both parts can be solved
+pub trait Solution: Sized {
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    fn parse(input: &str) -> Result<Self, ParseError>;
+
+    /// Overrides a parameter that differs between the samples and the actual input, like the
+    /// number of connections to make in 2025 day 8
+    fn set_param(&mut self, name: &str, _value: &str) -> Result<(), String> {
+        Err(format!("Unknown parameter: {}", name))
+    }
+
+    fn part_1(&self) -> Self::Answer1;
+
+    fn part_2(&self) -> Self::Answer2;
+
+    /// Renders the simulation of days that have one to show, passing its frames one at a time
+    fn render(&self, _frame: &mut dyn FnMut(Frame)) {}
+}
+
+/// Answer to one part of a puzzle, which is any displayable value unless the part is `Unsolved`
+pub trait Answer {
+    /// Whether the part is solved, which is known from the answer type alone
+    const SOLVED: bool = true;
+
+    fn to_answer(&self) -> Option<String>;
+}
+
+impl<T: Display> Answer for T {
+    fn to_answer(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+/// Answer type for a part that is not solved yet
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Unsolved;
+
+impl Answer for Unsolved {
+    const SOLVED: bool = false;
+
+    fn to_answer(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Answer to a solved part that some inputs have no answer for, like a sample that was made for
+/// the other part
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Maybe<T>(pub Option<T>);
+
+impl<T: Display> Answer for Maybe<T> {
+    fn to_answer(&self) -> Option<String> {
+        self.0.as_ref().map(T::to_string)
+    }
+}
+
+impl<T> From<Option<T>> for Maybe<T> {
+    fn from(answer: Option<T>) -> Self {
+        Self(answer)
+    }
+}
+
+/// Type-erased parsed solution, so days with different answer types can be treated uniformly
+pub trait Parts {
+    /// Solves the given part, returns `None` if the part is not solved yet
+    fn solve(&self, part: Part) -> Option<String>;
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String>;
+
+    fn render(&self, frame: &mut dyn FnMut(Frame));
+}
+
+impl<S: Solution> Parts for S {
+    fn solve(&self, part: Part) -> Option<String> {
+        match part {
+            Part::One => self.part_1().to_answer(),
+            Part::Two => self.part_2().to_answer(),
+        }
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        Solution::set_param(self, name, value)
+    }
+
+    fn render(&self, frame: &mut dyn FnMut(Frame)) {
+        Solution::render(self, frame)
+    }
+}
+
+pub type Parser = fn(&str) -> Result<Box<dyn Parts>, ParseError>;
+
+/// Parses the input, with errors pointing at their location in the input
+fn parse<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parts>, ParseError> {
+    Ok(Box::new(S::parse(input).map_err(|e| e.locate(input))?))
+}
+
+/// Registration of a single day's solution
+pub struct Day {
+    pub day: u8,
+    pub parse: Parser,
+    solved: [bool; 2],
+}
+
+impl Day {
+    pub const fn new<S: Solution + 'static>(day: u8) -> Self {
+        Self {
+            day,
+            parse: parse::<S>,
+            solved: [S::Answer1::SOLVED, S::Answer2::SOLVED],
+        }
+    }
+
+    /// Whether the solution solves the given part, without having to run it
+    pub fn is_solved(&self, part: Part) -> bool {
+        self.solved[part as usize]
+    }
+}
+
+/// All registered days of one year, along with where its inputs, given answers and learnings
+/// are stored
+pub struct Year {
+    pub year: u16,
+    pub input_dir: &'static str,
+    pub answers_path: &'static str,
+    pub learnings_path: &'static str,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub fn day(&self, day: u8) -> Option<&Day> {
+        self.days.iter().find(|d| d.day == day)
+    }
+
+    /// Default location of the puzzle input for the given day
+    pub fn input_path(&self, day: u8) -> String {
+        format!("{}/day{}.txt", self.input_dir, day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_parsing() {
+        assert_eq!(Ok(Part::One), "1".parse());
+        assert_eq!(Ok(Part::Two), "2".parse());
+        assert!("3".parse::<Part>().is_err());
+
+        for part in Part::ALL {
+            assert_eq!(Ok(part), part.to_string().parse());
+        }
+    }
+
+    struct Length(usize);
+
+    impl Solution for Length {
+        type Answer1 = usize;
+        type Answer2 = Unsolved;
+
+        fn parse(input: &str) -> Result<Self, ParseError> {
+            match input.trim().is_empty() {
+                true => Err(ParseError::after(input, "Empty input")),
+                false => Ok(Self(input.len())),
+            }
+        }
+
+        fn part_1(&self) -> Self::Answer1 {
+            self.0
+        }
+
+        fn part_2(&self) -> Self::Answer2 {
+            Unsolved
+        }
+    }
+
+    #[test]
+    fn test_parts() {
+        let parts = (Day::new::<Length>(1).parse)("abcd").unwrap();
+
+        assert_eq!(Some("4".to_string()), parts.solve(Part::One));
+        assert_eq!(None, parts.solve(Part::Two));
+        assert!(Day::new::<Length>(1).is_solved(Part::One));
+        assert!(!Day::new::<Length>(1).is_solved(Part::Two));
+        assert_eq!(
+            Some("line 2, column 1: Empty input".to_string()),
+            (Day::new::<Length>(1).parse)(" \n")
+                .err()
+                .map(|e| e.to_string())
+        );
+    }
+
+    #[test]
+    fn test_maybe() {
+        const { assert!(<Maybe<u8> as Answer>::SOLVED) };
+        assert_eq!(Some("3".to_string()), Maybe::from(Some(3)).to_answer());
+        assert_eq!(None, Maybe::<u8>(None).to_answer());
+    }
+
+    #[test]
+    fn test_year_lookup() {
+        const YEAR: Year = Year {
+            year: 2024,
+            input_dir: "input",
+            answers_path: "answers.txt",
+            learnings_path: "learnings.txt",
+            days: &[Day::new::<Length>(3)],
+        };
+
+        assert!(YEAR.day(1).is_none());
+        assert_eq!(3, YEAR.day(3).unwrap().day);
+        assert_eq!("input/day3.txt", YEAR.input_path(3));
+    }
+}
@@ -0,0 +1,515 @@
+use crate::grid::Grid;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Colour of a single pixel
+#[derive(Debug, Copy, Clone, Default, Hash, Eq, PartialEq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0, 0, 0);
+    pub const WHITE: Self = Self(255, 255, 255);
+    pub const GREY: Self = Self(110, 110, 110);
+    pub const RED: Self = Self(220, 50, 47);
+    pub const GREEN: Self = Self(80, 200, 80);
+    pub const BLUE: Self = Self(38, 139, 210);
+    pub const YELLOW: Self = Self(230, 190, 40);
+    pub const BROWN: Self = Self(150, 95, 45);
+}
+
+/// One frame of a rendered simulation, with a pixel per grid cell
+pub type Frame = Grid<Rgb>;
+
+/// Renders the frame with 24-bit ANSI colours for a terminal. Every character shows two pixels
+/// above each other: the upper half block in the colour of the top pixel, on a background in
+/// the colour of the bottom one.
+pub fn ansi(frame: &Frame) -> String {
+    let mut output = String::new();
+
+    for y in (0..frame.height()).step_by(2) {
+        let mut previous = None;
+
+        for x in 0..frame.width() {
+            let top = frame[(x, y)];
+            let bottom = frame.get((x, y + 1)).copied();
+
+            if previous != Some((top, bottom)) {
+                let Rgb(r, g, b) = top;
+                let _ = write!(output, "\x1b[38;2;{};{};{}m", r, g, b);
+                let _ = match bottom {
+                    Some(Rgb(r, g, b)) => write!(output, "\x1b[48;2;{};{};{}m", r, g, b),
+                    None => write!(output, "\x1b[49m"),
+                };
+                previous = Some((top, bottom));
+            }
+
+            output.push('▀');
+        }
+
+        output.push_str("\x1b[0m\n");
+    }
+
+    output
+}
+
+/// Scales the frame up, turning every pixel into a square of the given size
+pub fn scale(frame: &Frame, factor: usize) -> Frame {
+    let mut scaled = Frame::filled(frame.width() * factor, frame.height() * factor, Rgb::BLACK);
+
+    for (x, y) in scaled.positions() {
+        scaled[(x, y)] = frame[(x / factor, y / factor)];
+    }
+
+    scaled
+}
+
+/// Binary PPM image of the frame
+pub fn ppm(frame: &Frame) -> Vec<u8> {
+    let mut image = format!("P6\n{} {}\n255\n", frame.width(), frame.height()).into_bytes();
+
+    for &Rgb(r, g, b) in frame.rows().flatten() {
+        image.extend([r, g, b]);
+    }
+
+    image
+}
+
+/// PNG image of the frame. The image data is stored without compression, which keeps the
+/// encoder simple at the cost of file size.
+pub fn png(frame: &Frame) -> Vec<u8> {
+    // Scanlines each start with the filter type, which is none
+    let mut scanlines = vec![];
+    for row in frame.rows() {
+        scanlines.push(0);
+        for &Rgb(r, g, b) in row {
+            scanlines.extend([r, g, b]);
+        }
+    }
+
+    // Zlib stream of stored deflate blocks, which hold at most 65535 bytes each
+    let mut zlib = vec![0x78, 0x01];
+    let blocks = scanlines.chunks(u16::MAX as usize).collect::<Vec<_>>();
+    for (i, block) in blocks.iter().enumerate() {
+        let length = block.len() as u16;
+
+        zlib.push((i == blocks.len() - 1) as u8);
+        zlib.extend(length.to_le_bytes());
+        zlib.extend((!length).to_le_bytes());
+        zlib.extend(*block);
+    }
+    if blocks.is_empty() {
+        zlib.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    zlib.extend(adler32(&scanlines).to_be_bytes());
+
+    // Header: dimensions, 8 bits per channel, RGB colour type and default methods
+    let mut header = vec![];
+    header.extend((frame.width() as u32).to_be_bytes());
+    header.extend((frame.height() as u32).to_be_bytes());
+    header.extend([8, 2, 0, 0, 0]);
+
+    let mut image = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+    for (kind, data) in [(b"IHDR", &header), (b"IDAT", &zlib), (b"IEND", &vec![])] {
+        image.extend((data.len() as u32).to_be_bytes());
+        image.extend(kind);
+        image.extend(data);
+        image.extend(crc32(&[kind.as_slice(), data].concat()).to_be_bytes());
+    }
+
+    image
+}
+
+/// Animated GIF that loops forever, built up one frame at a time. Every frame has a colour
+/// table of its own, frames with more than 256 colours are reduced to a 6x6x6 colour cube.
+#[derive(Debug, Clone)]
+pub struct Gif {
+    /// Hundredths of a second each frame is shown
+    delay: u16,
+    dimensions: Option<(u16, u16)>,
+    frames: Vec<u8>,
+}
+
+impl Gif {
+    pub fn new(delay: u16) -> Self {
+        Self {
+            delay,
+            dimensions: None,
+            frames: vec![],
+        }
+    }
+
+    /// Appends a frame, which has to have the same size as the first one
+    pub fn add_frame(&mut self, frame: &Frame) -> Result<(), String> {
+        let dimensions = match (u16::try_from(frame.width()), u16::try_from(frame.height())) {
+            (Ok(width), Ok(height)) => (width, height),
+            _ => return Err("Frame is too large for a GIF".to_string()),
+        };
+        if *self.dimensions.get_or_insert(dimensions) != dimensions {
+            return Err("All frames of a GIF need to have the same size".to_string());
+        }
+
+        let (palette, indices) = match palette(frame.rows().flatten().copied()) {
+            Some(palette) => palette,
+            None => palette(frame.rows().flatten().map(|&pixel| pixel.quantize())).unwrap(),
+        };
+        // The table size is a power of two of at least 2 bits, which the LZW codes start from
+        let bits = (palette.len().next_power_of_two().trailing_zeros()).max(2);
+
+        // Graphic control extension: keep the frame when drawing the next one, and its delay
+        self.frames.extend([0x21, 0xf9, 4, 1 << 2]);
+        self.frames.extend(self.delay.to_le_bytes());
+        self.frames.extend([0, 0]);
+
+        // Image descriptor covering the whole screen, with a local colour table
+        self.frames.extend([0x2c, 0, 0, 0, 0]);
+        self.frames.extend(dimensions.0.to_le_bytes());
+        self.frames.extend(dimensions.1.to_le_bytes());
+        self.frames.push(0x80 | (bits - 1) as u8);
+        for index in 0..1 << bits {
+            let Rgb(r, g, b) = palette.get(index).copied().unwrap_or_default();
+            self.frames.extend([r, g, b]);
+        }
+
+        // Image data in sub-blocks of at most 255 bytes, ended by an empty one
+        self.frames.push(bits as u8);
+        for block in lzw(&indices, bits).chunks(255) {
+            self.frames.push(block.len() as u8);
+            self.frames.extend(block);
+        }
+        self.frames.push(0);
+
+        Ok(())
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        let (width, height) = self.dimensions.unwrap_or((0, 0));
+
+        // Logical screen without a global colour table
+        let mut image = b"GIF89a".to_vec();
+        image.extend(width.to_le_bytes());
+        image.extend(height.to_le_bytes());
+        image.extend([0, 0, 0]);
+
+        // Application extension to loop the animation forever
+        image.extend([0x21, 0xff, 11]);
+        image.extend(b"NETSCAPE2.0");
+        image.extend([3, 1, 0, 0, 0]);
+
+        image.extend(self.frames);
+        image.push(0x3b);
+
+        image
+    }
+}
+
+impl Rgb {
+    /// Nearest colour of the 6x6x6 colour cube
+    fn quantize(self) -> Self {
+        let level = |channel: u8| (channel as u32 * 5 + 127) / 255 * 51;
+
+        Self(
+            level(self.0) as u8,
+            level(self.1) as u8,
+            level(self.2) as u8,
+        )
+    }
+}
+
+/// Distinct colours of the pixels and the pixels as indices into them, unless there are more
+/// colours than fit a GIF colour table
+fn palette(pixels: impl Iterator<Item = Rgb>) -> Option<(Vec<Rgb>, Vec<u8>)> {
+    let mut palette = vec![];
+    let mut indices = HashMap::new();
+
+    let pixels = pixels
+        .map(|pixel| {
+            let index = *indices.entry(pixel).or_insert_with(|| {
+                palette.push(pixel);
+                palette.len() - 1
+            });
+            u8::try_from(index).ok()
+        })
+        .collect::<Option<_>>()?;
+
+    Some((palette, pixels))
+}
+
+/// Compresses the colour indices with the variable code size LZW of GIF, starting from codes
+/// of one bit more than the given size. The code table is reset when it is full.
+fn lzw(indices: &[u8], bits: u32) -> Vec<u8> {
+    let clear = 1 << bits;
+    let end = clear + 1;
+
+    let mut codes: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next = end + 1;
+    let mut size = bits + 1;
+    let mut output = vec![];
+    let mut buffer = 0u32;
+    let mut buffered = 0;
+
+    // Packs the code least significant bit first, after which the code size grows once the
+    // next code no longer fits
+    let mut emit = |code: u16, next: u16, size: &mut u32| {
+        buffer |= (code as u32) << buffered;
+        buffered += *size;
+        while buffered >= 8 {
+            output.push(buffer as u8);
+            buffer >>= 8;
+            buffered -= 8;
+        }
+        if next >= 1 << *size && *size < 12 {
+            *size += 1;
+        }
+    };
+
+    emit(clear, next, &mut size);
+    let mut prefix = None;
+    for &index in indices {
+        prefix = Some(match prefix {
+            None => index as u16,
+            Some(prefix) => match codes.get(&(prefix, index)) {
+                Some(&code) => code,
+                None => {
+                    emit(prefix, next, &mut size);
+                    if next == 4095 {
+                        emit(clear, next, &mut size);
+                        codes.clear();
+                        next = end + 1;
+                        size = bits + 1;
+                    } else {
+                        codes.insert((prefix, index), next);
+                        next += 1;
+                    }
+                    index as u16
+                }
+            },
+        });
+    }
+    if let Some(prefix) = prefix {
+        emit(prefix, next, &mut size);
+    }
+    emit(end, next, &mut size);
+    if buffered > 0 {
+        output.push(buffer as u8);
+    }
+
+    output
+}
+
+/// Checksum of the PNG chunks
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, &byte| {
+        (0..8).fold(crc ^ byte as u32, |crc, _| match crc & 1 {
+            1 => (crc >> 1) ^ 0xedb88320,
+            _ => crc >> 1,
+        })
+    })
+}
+
+/// Checksum of the zlib stream
+fn adler32(data: &[u8]) -> u32 {
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Frame {
+        Grid::new(
+            2,
+            3,
+            vec![
+                Rgb::RED,
+                Rgb::RED,
+                Rgb::BLUE,
+                Rgb::RED,
+                Rgb::WHITE,
+                Rgb::BLACK,
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_ansi() {
+        assert_eq!(
+            "\x1b[38;2;220;50;47m\x1b[48;2;38;139;210m▀\x1b[38;2;220;50;47m\x1b[48;2;220;50;47m▀\x1b[0m\n\
+             \x1b[38;2;255;255;255m\x1b[49m▀\x1b[38;2;0;0;0m\x1b[49m▀\x1b[0m\n",
+            ansi(&frame())
+        );
+    }
+
+    #[test]
+    fn test_scale() {
+        let scaled = scale(&frame(), 2);
+
+        assert_eq!((4, 6), (scaled.width(), scaled.height()));
+        assert_eq!(Rgb::RED, scaled[(1, 1)]);
+        assert_eq!(Rgb::BLUE, scaled[(1, 2)]);
+        assert_eq!(Rgb::BLACK, scaled[(3, 5)]);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let image = ppm(&frame());
+
+        assert!(image.starts_with(b"P6\n2 3\n255\n"));
+        assert_eq!(b"P6\n2 3\n255\n".len() + 2 * 3 * 3, image.len());
+        assert_eq!([220, 50, 47, 220, 50, 47, 38, 139, 210], image[11..20]);
+    }
+
+    #[test]
+    fn test_png() {
+        let image = png(&frame());
+
+        assert_eq!(b"\x89PNG\r\n\x1a\n", &image[..8]);
+        assert_eq!(
+            b"\0\0\0\x0dIHDR\0\0\0\x02\0\0\0\x03\x08\x02\0\0\0",
+            &image[8..29]
+        );
+        assert_eq!(b"\0\0\0\0IEND\xae\x42\x60\x82", &image[image.len() - 12..]);
+
+        // 3 scanlines of a filter byte and 2 pixels in a single stored block
+        assert_eq!(b"IDAT\x78\x01\x01\x15\0\xea\xff\0", &image[37..49]);
+    }
+
+    /// Decompresses the LZW codes of a GIF frame
+    fn unlzw(data: &[u8], bits: usize) -> Vec<u8> {
+        let clear = 1 << bits;
+        let reset = || (0..clear + 2).map(|i| vec![i as u8]).collect::<Vec<_>>();
+
+        let mut table = reset();
+        let mut size = bits + 1;
+        let mut position = 0;
+        let mut previous: Option<Vec<u8>> = None;
+        let mut output = vec![];
+
+        loop {
+            let code = (0..size).fold(0, |code, i| {
+                let bit = (data[(position + i) / 8] >> ((position + i) % 8)) & 1;
+                code | (bit as usize) << i
+            });
+            position += size;
+
+            if code == clear {
+                table = reset();
+                size = bits + 1;
+                previous = None;
+                continue;
+            }
+            if code == clear + 1 {
+                return output;
+            }
+
+            let entry = match table.get(code) {
+                Some(entry) => entry.clone(),
+                None => {
+                    let previous = previous.clone().unwrap();
+                    [previous.as_slice(), &previous[..1]].concat()
+                }
+            };
+            output.extend(&entry);
+            if let Some(previous) = previous {
+                table.push([previous.as_slice(), &entry[..1]].concat());
+            }
+            if table.len() == 1 << size && size < 12 {
+                size += 1;
+            }
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn test_lzw() {
+        for (indices, bits) in [
+            (vec![], 2),
+            (vec![0, 0, 0, 0, 0, 0, 0], 2),
+            (vec![1, 0, 1, 0, 1, 0, 1, 1, 1, 2, 3, 3, 3, 1], 2),
+            // Enough distinct sequences to fill the code table several times
+            ((0..40_000u32).map(|i| (i * i / 7 % 251) as u8).collect(), 8),
+        ] {
+            assert_eq!(indices, unlzw(&lzw(&indices, bits as u32), bits));
+        }
+    }
+
+    #[test]
+    fn test_lzw_reference() {
+        // The 10x10 image of the walkthrough "What's in a GIF" by Matthew Flickinger, with the
+        // image data its encoder wrote for a minimum code size of 2
+        let indices = [
+            "1111122222",
+            "1111122222",
+            "1111122222",
+            "1110000222",
+            "1110000222",
+            "2220000111",
+            "2220000111",
+            "2222211111",
+            "2222211111",
+            "2222211111",
+        ]
+        .concat()
+        .bytes()
+        .map(|digit| digit - b'0')
+        .collect::<Vec<_>>();
+        const IMAGE_DATA: [u8; 22] = [
+            0x8c, 0x2d, 0x99, 0x87, 0x2a, 0x1c, 0xdc, 0x33, 0xa0, 0x02, 0x75, 0xec, 0x95, 0xfa,
+            0xa8, 0xde, 0x60, 0x8c, 0x04, 0x91, 0x4c, 0x01,
+        ];
+
+        assert_eq!(IMAGE_DATA.to_vec(), lzw(&indices, 2));
+    }
+
+    #[test]
+    fn test_gif() {
+        let mut gif = Gif::new(5);
+        gif.add_frame(&frame()).unwrap();
+        gif.add_frame(&scale(&frame(), 1)).unwrap();
+        assert!(gif.add_frame(&scale(&frame(), 2)).is_err());
+        let image = gif.finish();
+
+        assert_eq!(b"GIF89a\x02\0\x03\0\0\0\0", &image[..13]);
+        assert_eq!(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\0\0\0", &image[13..32]);
+        assert_eq!(b"\x21\xf9\x04\x04\x05\0\0\0", &image[32..40]);
+        assert_eq!(b"\x2c\0\0\0\0\x02\0\x03\0\x81", &image[40..50]);
+        assert_eq!(0x3b, image[image.len() - 1]);
+
+        // Red, blue, white and black make up the colour table of 4 colours
+        assert_eq!(
+            [220, 50, 47, 38, 139, 210, 255, 255, 255, 0, 0, 0],
+            image[50..62]
+        );
+        let data = &image[64..64 + image[63] as usize];
+        assert_eq!(vec![0, 0, 1, 0, 2, 3], unlzw(data, image[62] as usize));
+    }
+
+    #[test]
+    fn test_quantize() {
+        let frame = Grid::new(
+            300,
+            1,
+            (0..300).map(|i| Rgb(i as u8, (i / 256) as u8, 0)).collect(),
+        )
+        .unwrap();
+        let mut gif = Gif::new(0);
+        gif.add_frame(&frame).unwrap();
+
+        assert_eq!(Rgb(255, 0, 0), Rgb(230, 20, 0).quantize());
+        assert_eq!(Rgb(153, 204, 51), Rgb(160, 190, 30).quantize());
+
+        // 300 colours are reduced to the 6 levels of red, in a colour table of 8
+        assert_eq!(0x82, gif.finish()[49]);
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(0xcbf43926, crc32(b"123456789"));
+        assert_eq!(0x11e60398, adler32(b"Wikipedia"));
+    }
+}
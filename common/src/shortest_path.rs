@@ -0,0 +1,283 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Cost of a path, like a number of steps or points
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// State waiting to be explored, ordered so the binary heap pops the lowest priority first
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+/// Outcome of a search: the lowest cost of every state that was reached, and for each of them
+/// the states it is reached from at that cost. Together the latter form a DAG of all optimal
+/// paths from the start.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S, C> {
+    start: S,
+    costs: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+/// Dijkstra's algorithm from the start until the goal is reached, or until every reachable
+/// state is explored when there is none. `neighbours` gives the states a state leads to, with
+/// the cost of getting there.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl Fn(&S) -> bool,
+) -> ShortestPaths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search, which explores the states in order of their cost plus the heuristic. The
+/// heuristic has to be consistent (never overestimate a step) for the paths to be optimal.
+pub fn astar<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    heuristic: impl Fn(&S) -> C,
+    is_goal: impl Fn(&S) -> bool,
+) -> ShortestPaths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut paths = ShortestPaths {
+        start: start.clone(),
+        costs: HashMap::from([(start.clone(), C::default())]),
+        predecessors: HashMap::new(),
+        goals: vec![],
+    };
+    let mut frontier = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    }]);
+    let mut goal_cost = None;
+
+    while let Some(Entry {
+        priority,
+        cost,
+        state,
+    }) = frontier.pop()
+    {
+        // Keep going until no state is left that could reach a goal at the same cost, to find
+        // all optimal paths
+        if goal_cost.is_some_and(|goal_cost| priority > goal_cost) {
+            break;
+        }
+        // Skip states that were reached at a lower cost after they were queued
+        if cost > paths.costs[&state] {
+            continue;
+        }
+        if is_goal(&state) {
+            goal_cost = Some(cost);
+            paths.goals.push(state);
+            continue;
+        }
+
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+
+            match paths.costs.get(&next) {
+                Some(&known) if next_cost > known => {}
+                Some(&known) if next_cost == known => {
+                    paths
+                        .predecessors
+                        .entry(next)
+                        .or_default()
+                        .push(state.clone());
+                }
+                _ => {
+                    paths.costs.insert(next.clone(), next_cost);
+                    paths.predecessors.insert(next.clone(), vec![state.clone()]);
+                    frontier.push(Entry {
+                        priority: next_cost + heuristic(&next),
+                        cost: next_cost,
+                        state: next,
+                    });
+                }
+            }
+        }
+    }
+
+    paths
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> ShortestPaths<S, C> {
+    /// Lowest cost of reaching a goal
+    pub fn cost(&self) -> Option<C> {
+        self.goals.first().map(|goal| self.costs[goal])
+    }
+
+    /// Lowest cost of reaching the given state, which is only known for certain for states
+    /// that were explored before the search stopped
+    pub fn cost_to(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// Goal states that are reached at the lowest cost
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// States leading to the given state on its optimal paths
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// One of the optimal paths to a goal, from the start up to and including the goal
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goals.first()?)
+    }
+
+    /// One of the optimal paths to the given state, from the start up to and including it
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.costs.get(state)?;
+
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Every state on any of the optimal paths to the goals
+    pub fn on_optimal_paths(&self) -> HashSet<S> {
+        let mut states: HashSet<S> = self.goals.iter().cloned().collect();
+        let mut pending = self.goals.clone();
+
+        while let Some(state) = pending.pop() {
+            for previous in self.predecessors(&state) {
+                if states.insert(previous.clone()) {
+                    pending.push(previous.clone());
+                }
+            }
+        }
+        if !self.goals.is_empty() {
+            states.insert(self.start.clone());
+        }
+
+        states
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, Position};
+
+    fn maze() -> Grid<bool> {
+        // true for walls
+        Grid::parse_with(
+            r#"
+.....
+.###.
+...#.
+.#...
+"#,
+            |char| Ok::<_, &str>(char == '#'),
+        )
+        .unwrap()
+    }
+
+    fn steps(maze: &Grid<bool>) -> impl FnMut(&Position) -> Vec<(Position, u32)> + '_ {
+        |&pos| {
+            maze.neighbours_4(pos)
+                .filter(|&next| !maze[next])
+                .map(|next| (next, 1))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let maze = maze();
+        let paths = dijkstra((0, 0), steps(&maze), |&pos| pos == (4, 2));
+
+        assert_eq!(Some(6), paths.cost());
+        assert_eq!(&[(4, 2)], paths.goals());
+        assert_eq!(
+            Some(vec![(0, 0), (1, 0), (2, 0), (3, 0), (4, 0), (4, 1), (4, 2)]),
+            paths.path()
+        );
+        assert_eq!(Some(2), paths.cost_to(&(0, 2)));
+        assert_eq!(&[(0, 1)], paths.predecessors(&(0, 2)));
+        assert_eq!(None, paths.path_to(&(1, 1)));
+
+        // Both ways around the walls are optimal
+        let paths = dijkstra((0, 0), steps(&maze), |&pos| pos == (4, 3));
+        assert_eq!(Some(7), paths.cost());
+        assert_eq!(8, paths.path().unwrap().len());
+        assert_eq!(2, paths.predecessors(&(4, 3)).len());
+        assert_eq!(
+            maze.iter()
+                .filter(|&(pos, &wall)| !wall && pos != (0, 3))
+                .map(|(pos, _)| pos)
+                .collect::<HashSet<_>>(),
+            paths.on_optimal_paths()
+        );
+
+        let paths = dijkstra((0, 0), steps(&maze), |_| false);
+        assert_eq!(None, paths.cost());
+        assert_eq!(None, paths.path());
+        assert_eq!(Some(7), paths.cost_to(&(4, 3)));
+        assert!(paths.on_optimal_paths().is_empty());
+    }
+
+    #[test]
+    fn test_astar() {
+        let maze = Grid::filled(20, 20, false);
+        let manhattan = |&(x, y): &Position| (19 - x + 19 - y) as u32;
+        let paths = astar((0, 0), steps(&maze), manhattan, |&pos| pos == (19, 19));
+
+        assert_eq!(Some(38), paths.cost());
+        assert_eq!(39, paths.path().unwrap().len());
+        assert_eq!(400, paths.on_optimal_paths().len());
+
+        // The heuristic keeps the search along the single optimal path, unlike Dijkstra
+        let manhattan = |&(x, y): &Position| (19 - x + y) as u32;
+        let paths = astar((0, 0), steps(&maze), manhattan, |&pos| pos == (19, 0));
+        assert_eq!(Some(19), paths.cost());
+        assert_eq!(20, paths.on_optimal_paths().len());
+        assert_eq!(None, paths.cost_to(&(0, 19)));
+
+        let paths = dijkstra((0, 0), steps(&maze), |&pos| pos == (19, 0));
+        assert_eq!(Some(19), paths.cost_to(&(0, 19)));
+    }
+}